@@ -0,0 +1,30 @@
+use crate::core::function::{Function, Type};
+
+/// Entry points called by the protocol to validate the transactions of an account contract
+const VALIDATE_ENTRY_POINTS: [&str; 3] = [
+    "__validate__",
+    "__validate_declare__",
+    "__validate_deploy__",
+];
+
+/// Entry point called by the protocol to execute the calls of an account contract
+const EXECUTE_ENTRY_POINT: &str = "__execute__";
+
+/// Return true if the function is an account contract entry point validating a transaction
+pub fn is_validate_entry_point(function: &Function) -> bool {
+    is_entry_point_named(function, |name| VALIDATE_ENTRY_POINTS.contains(&name))
+}
+
+/// Return true if the function is the account contract entry point executing a transaction
+pub fn is_execute_entry_point(function: &Function) -> bool {
+    is_entry_point_named(function, |name| name == EXECUTE_ENTRY_POINT)
+}
+
+/// The entry points are part of the ABI, they are view functions when they take the state as snapshot
+fn is_entry_point_named(function: &Function, is_name: impl Fn(&str) -> bool) -> bool {
+    matches!(function.ty(), Type::External | Type::View)
+        && function
+            .name()
+            .rsplit_once("::")
+            .is_some_and(|(_, name)| is_name(name))
+}
@@ -0,0 +1,59 @@
+use super::def_use::{Definition, Value};
+use crate::core::compilation_unit::CompilationUnit;
+use crate::core::function::Function;
+use cairo_lang_sierra::extensions::core::CoreConcreteLibfunc;
+use cairo_lang_sierra::extensions::structure::StructConcreteLibfunc;
+use cairo_lang_sierra::ids::VarId;
+use cairo_lang_sierra::program::GenStatement;
+
+/// Index of the block_number member of BlockInfo
+const BLOCK_NUMBER_MEMBER: usize = 0;
+/// Index of the block_timestamp member of BlockInfo
+const BLOCK_TIMESTAMP_MEMBER: usize = 1;
+
+/// Return the values holding the block number and the block timestamp in the function
+/// returned by get_block_number and get_block_timestamp or read from a BlockInfo
+/// e.g. get_block_info().unbox().block_number or get_execution_info().unbox().block_info.unbox().block_timestamp
+pub fn block_info_fields(
+    compilation_unit: &CompilationUnit,
+    function: &Function,
+) -> Vec<(&'static str, Vec<Value>)> {
+    let mut number = Vec::new();
+    let mut timestamp = Vec::new();
+
+    for (i, stmt) in function.get_statements().iter().enumerate() {
+        let pc = function.entry_point() + i;
+        if let GenStatement::Invocation(invoc) = stmt {
+            let libfunc = compilation_unit
+                .registry()
+                .get_libfunc(&invoc.libfunc_id)
+                .expect("Library function not found in the registry");
+            let value = |var: &VarId| Value::new(var.clone(), Definition::Statement(pc));
+
+            match libfunc {
+                CoreConcreteLibfunc::Struct(StructConcreteLibfunc::Deconstruct(struct_type))
+                    if struct_type.signature.param_signatures[0].ty.to_string()
+                        == "core::starknet::info::BlockInfo" =>
+                {
+                    let results = &invoc.branches[0].results;
+                    number.extend(results.get(BLOCK_NUMBER_MEMBER).map(value));
+                    timestamp.extend(results.get(BLOCK_TIMESTAMP_MEMBER).map(value));
+                }
+                CoreConcreteLibfunc::FunctionCall(f_called) => {
+                    let fields = match f_called.function.id.debug_name.as_deref() {
+                        Some("core::starknet::info::get_block_number") => &mut number,
+                        Some("core::starknet::info::get_block_timestamp") => &mut timestamp,
+                        _ => continue,
+                    };
+                    fields.extend(invoc.branches[0].results.last().map(value));
+                }
+                _ => (),
+            }
+        }
+    }
+
+    [("number", number), ("timestamp", timestamp)]
+        .into_iter()
+        .filter(|(_, values)| !values.is_empty())
+        .collect()
+}
@@ -0,0 +1,537 @@
+use std::collections::HashSet;
+
+use super::def_use::{Definition, Value};
+use super::panics::{always_panics_from, is_panic_block};
+use super::taint::WrapperVariable;
+use crate::core::cfg::Cfg;
+use crate::core::compilation_unit::CompilationUnit;
+use crate::core::function::{Function, Type};
+use crate::utils::{branch_target, called_function_name, storage_var_name};
+use cairo_lang_sierra::extensions::boolean::BoolConcreteLibfunc;
+use cairo_lang_sierra::extensions::core::CoreConcreteLibfunc;
+use cairo_lang_sierra::extensions::enm::EnumConcreteLibfunc;
+use cairo_lang_sierra::extensions::felt252::Felt252Concrete;
+use cairo_lang_sierra::extensions::starknet::StarkNetConcreteLibfunc;
+use cairo_lang_sierra::extensions::structure::StructConcreteLibfunc;
+use cairo_lang_sierra::ids::VarId;
+use cairo_lang_sierra::program::{GenStatement, Invocation};
+use fxhash::FxHashSet;
+
+const EXECUTION_INFO_TYPES: [&str; 2] = [
+    "core::starknet::info::ExecutionInfo",
+    "core::starknet::info::v2::ExecutionInfo",
+];
+
+/// Return the pc of the statements of the function checking the caller address
+/// i.e. a felt252_is_zero comparing a value derived from get_caller_address
+/// or a call to a function returning only when such check passes, directly or in the functions it calls
+pub fn caller_checks(compilation_unit: &CompilationUnit, function: &Function) -> Vec<usize> {
+    let mut visiting = HashSet::new();
+    caller_checks_helper(
+        compilation_unit,
+        function,
+        &FxHashSet::default(),
+        &mut visiting,
+    )
+}
+
+/// Return true if the function or the functions it calls check the caller address
+pub fn has_caller_check(compilation_unit: &CompilationUnit, function: &Function) -> bool {
+    !caller_checks(compilation_unit, function).is_empty()
+}
+
+/// Return the storage variables compared with the caller address e.g. the owner of the contract
+/// i.e. a value read from them reaches a felt252_is_zero checking the caller address
+pub fn authorization_storage_vars(compilation_unit: &CompilationUnit) -> HashSet<String> {
+    let mut storage_vars = HashSet::new();
+
+    for function in compilation_unit.functions_user_defined() {
+        let taint = match compilation_unit.get_taint(&function.name()) {
+            Some(taint) => taint,
+            None => continue,
+        };
+        let checks = caller_checks(compilation_unit, function);
+
+        for stmt in function.get_statements() {
+            let invoc = match stmt {
+                GenStatement::Invocation(invoc) => invoc,
+                GenStatement::Return(_) => continue,
+            };
+            let name = match called_function_name(compilation_unit.registry(), stmt) {
+                Some(name)
+                    if name.ends_with("read")
+                        && compilation_unit
+                            .function_by_name(&name)
+                            .is_some_and(|f| *f.ty() == Type::Storage) =>
+                {
+                    name
+                }
+                _ => continue,
+            };
+            let read_values: FxHashSet<WrapperVariable> = invoc.branches[0]
+                .results
+                .iter()
+                .map(|result| WrapperVariable::new(function.name(), result.id))
+                .collect();
+
+            // Only the checks done in the function, not the calls to a function doing a check
+            let compared = checks.iter().any(|check| {
+                match &function.get_statements()[check - function.entry_point()] {
+                    GenStatement::Invocation(check) => {
+                        matches!(
+                            compilation_unit
+                                .registry()
+                                .get_libfunc(&check.libfunc_id)
+                                .expect("Library function not found in the registry"),
+                            CoreConcreteLibfunc::Felt252(Felt252Concrete::IsZero(_))
+                        ) && taint.taints_any_sources(
+                            &read_values,
+                            &WrapperVariable::new(function.name(), check.args[0].id),
+                        )
+                    }
+                    GenStatement::Return(_) => false,
+                }
+            });
+            if compared {
+                storage_vars.insert(storage_var_name(&name).to_string());
+            }
+        }
+    }
+
+    storage_vars
+}
+
+/// Return true if the instruction at pc of the function is executed only when a caller check passes
+/// For private functions the check can be done before every call to the function
+pub fn is_protected_by_caller_check(
+    compilation_unit: &CompilationUnit,
+    function: &Function,
+    pc: usize,
+) -> bool {
+    let mut visiting = HashSet::new();
+    is_protected_helper(compilation_unit, function, pc, &mut visiting)
+}
+
+fn is_protected_helper(
+    compilation_unit: &CompilationUnit,
+    function: &Function,
+    pc: usize,
+    visiting: &mut HashSet<String>,
+) -> bool {
+    // The constructor can be called only once when the contract is deployed
+    if *function.ty() == Type::Constructor {
+        return true;
+    }
+
+    if caller_checks(compilation_unit, function)
+        .into_iter()
+        .any(|check| is_enforced_at(compilation_unit, function, check, pc))
+    {
+        return true;
+    }
+
+    // An entry point can be called by anyone, a recursive function could call itself without the check
+    if matches!(function.ty(), Type::External | Type::View | Type::L1Handler)
+        || !visiting.insert(function.name())
+    {
+        return false;
+    }
+
+    let mut call_sites = Vec::new();
+    for caller in compilation_unit
+        .callgraph()
+        .callers(&function.name())
+        .filter_map(|name| compilation_unit.function_by_name(name))
+    {
+        for (i, stmt) in caller.get_statements().iter().enumerate() {
+            if let GenStatement::Invocation(invoc) = stmt {
+                if let CoreConcreteLibfunc::FunctionCall(f_called) = compilation_unit
+                    .registry()
+                    .get_libfunc(&invoc.libfunc_id)
+                    .expect("Library function not found in the registry")
+                {
+                    if f_called.function.id.debug_name.as_ref().unwrap().as_str() == function.name()
+                    {
+                        call_sites.push((caller, caller.entry_point() + i));
+                    }
+                }
+            }
+        }
+    }
+
+    let protected = !call_sites.is_empty()
+        && call_sites
+            .into_iter()
+            .all(|(caller, pc)| is_protected_helper(compilation_unit, caller, pc, visiting));
+    visiting.remove(&function.name());
+    protected
+}
+
+/// Return true if the instruction at pc is executed only when the caller check at check passes
+/// i.e. it is dominated by the branch where the caller matches or the other branch always panics
+/// A call to a function doing a caller check passes only when the check does
+fn is_enforced_at(
+    compilation_unit: &CompilationUnit,
+    function: &Function,
+    check: usize,
+    pc: usize,
+) -> bool {
+    if check == pc {
+        return false;
+    }
+    let invoc = match &function.get_statements()[check - function.entry_point()] {
+        GenStatement::Invocation(invoc) => invoc,
+        GenStatement::Return(_) => return false,
+    };
+
+    match compilation_unit
+        .registry()
+        .get_libfunc(&invoc.libfunc_id)
+        .expect("Library function not found in the registry")
+    {
+        // The first branch is taken when the difference with the caller address is zero
+        CoreConcreteLibfunc::Felt252(Felt252Concrete::IsZero(_)) => {
+            function.instruction_dominates(branch_target(&invoc.branches[0], check), pc)
+                || (function.instruction_dominates(check, pc)
+                    && always_panics_from(
+                        function,
+                        compilation_unit.registry(),
+                        branch_target(&invoc.branches[1], check),
+                    ))
+        }
+        _ => function.instruction_dominates(check, pc),
+    }
+}
+
+/// Return true if the function returns without panicking only when one of its caller checks passes
+fn enforces_caller_check(
+    compilation_unit: &CompilationUnit,
+    function: &Function,
+    checks: &[usize],
+) -> bool {
+    !checks.is_empty()
+        && function
+            .get_cfg()
+            .get_basic_blocks()
+            .iter()
+            .filter(|bb| {
+                bb.get_outgoing_basic_blocks().is_empty()
+                    && !is_panic_block(bb, compilation_unit.registry())
+            })
+            .filter_map(|bb| bb.last_instruction().map(|i| i.get_pc()))
+            .all(|exit| {
+                checks
+                    .iter()
+                    .any(|check| is_enforced_at(compilation_unit, function, *check, exit))
+            })
+}
+
+/// caller_addresses are the variables of the function derived from the caller address
+/// when the function is called with the caller address as argument
+fn caller_checks_helper(
+    compilation_unit: &CompilationUnit,
+    function: &Function,
+    caller_addresses: &FxHashSet<WrapperVariable>,
+    visiting: &mut HashSet<String>,
+) -> Vec<usize> {
+    let mut checks = Vec::new();
+    let taint = match compilation_unit.get_taint(&function.name()) {
+        Some(taint) => taint,
+        None => return checks,
+    };
+    visiting.insert(function.name());
+
+    let mut sources = caller_addresses.clone();
+    sources.extend(caller_address_sources(compilation_unit, function));
+
+    for (i, stmt) in function.get_statements().iter().enumerate() {
+        let pc = function.entry_point() + i;
+        if let GenStatement::Invocation(invoc) = stmt {
+            let libfunc = compilation_unit
+                .registry()
+                .get_libfunc(&invoc.libfunc_id)
+                .expect("Library function not found in the registry");
+
+            match libfunc {
+                CoreConcreteLibfunc::Felt252(Felt252Concrete::IsZero(_)) => {
+                    let is_caller = |var: &VarId| {
+                        taint.taints_any_sources(
+                            &sources,
+                            &WrapperVariable::new(function.name(), var.id),
+                        )
+                    };
+                    // Checking the caller address is zero only tells the protocol is calling
+                    if is_caller(&invoc.args[0])
+                        && address_zero_check(compilation_unit, function, pc, &is_caller).is_none()
+                    {
+                        checks.push(pc);
+                    }
+                }
+                CoreConcreteLibfunc::FunctionCall(f_called) => {
+                    let called = match compilation_unit
+                        .function_by_name(f_called.function.id.debug_name.as_ref().unwrap())
+                    {
+                        Some(called)
+                            if matches!(
+                                called.ty(),
+                                Type::Private | Type::Loop | Type::External | Type::View
+                            ) && !visiting.contains(&called.name()) =>
+                        {
+                            called
+                        }
+                        _ => continue,
+                    };
+
+                    // Map the arguments derived from the caller address to the parameters of the function called
+                    let called_caller_addresses: FxHashSet<WrapperVariable> = invoc
+                        .args
+                        .iter()
+                        .zip(called.params_all())
+                        .filter(|(arg, _)| {
+                            taint.taints_any_sources(
+                                &sources,
+                                &WrapperVariable::new(function.name(), arg.id),
+                            )
+                        })
+                        .map(|(_, param)| WrapperVariable::new(called.name(), param.id.id))
+                        .collect();
+
+                    let called_checks = caller_checks_helper(
+                        compilation_unit,
+                        called,
+                        &called_caller_addresses,
+                        visiting,
+                    );
+                    if enforces_caller_check(compilation_unit, called, &called_checks) {
+                        checks.push(pc);
+                    }
+                }
+                _ => (),
+            }
+        }
+    }
+
+    visiting.remove(&function.name());
+    checks
+}
+
+/// Return the variables of the function holding the caller address
+fn caller_address_sources(
+    compilation_unit: &CompilationUnit,
+    function: &Function,
+) -> FxHashSet<WrapperVariable> {
+    caller_address_values(compilation_unit, function)
+        .into_iter()
+        .map(|value| WrapperVariable::new(function.name(), value.var.id))
+        .collect()
+}
+
+/// Return the values of the function holding the caller address
+/// returned by get_caller_address or read from an ExecutionInfo
+pub fn caller_address_values(
+    compilation_unit: &CompilationUnit,
+    function: &Function,
+) -> Vec<Value> {
+    function
+        .get_statements()
+        .iter()
+        .enumerate()
+        .filter_map(|(i, stmt)| match stmt {
+            GenStatement::Invocation(invoc) => {
+                let libfunc = compilation_unit
+                    .registry()
+                    .get_libfunc(&invoc.libfunc_id)
+                    .expect("Library function not found in the registry");
+
+                let caller = match libfunc {
+                    // The last value returned is the caller address, the others are the builtins
+                    CoreConcreteLibfunc::FunctionCall(f_called)
+                        if f_called
+                            .function
+                            .id
+                            .debug_name
+                            .as_ref()
+                            .unwrap()
+                            .ends_with("::get_caller_address") =>
+                    {
+                        invoc.branches[0].results.last()
+                    }
+                    // When get_caller_address is inlined we have the deconstruction of the ExecutionInfo
+                    // where the third member is the caller address
+                    CoreConcreteLibfunc::Struct(StructConcreteLibfunc::Deconstruct(
+                        struct_type,
+                    )) if struct_type
+                        .signature
+                        .param_signatures
+                        .first()
+                        .is_some_and(|p| {
+                            EXECUTION_INFO_TYPES.contains(&p.ty.to_string().as_str())
+                        }) =>
+                    {
+                        invoc.branches[0].results.get(2)
+                    }
+                    _ => None,
+                };
+                caller.map(|var| {
+                    Value::new(
+                        var.clone(),
+                        Definition::Statement(function.entry_point() + i),
+                    )
+                })
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+/// Return true if the function panics when the caller address is not zero i.e. it can only be called by the protocol
+/// The execution must continue only in the zero branch of a zero check of the caller address
+/// e.g. assert(get_caller_address().is_zero())
+pub fn is_only_callable_by_protocol(
+    compilation_unit: &CompilationUnit,
+    function: &Function,
+) -> bool {
+    let taint = match compilation_unit.get_taint(&function.name()) {
+        Some(taint) => taint,
+        None => return false,
+    };
+    let callers = caller_address_sources(compilation_unit, function);
+    let is_caller = |var: &VarId| {
+        taint.taints_any_sources(&callers, &WrapperVariable::new(function.name(), var.id))
+    };
+
+    function
+        .get_statements()
+        .iter()
+        .enumerate()
+        .any(|(i, stmt)| {
+            let pc = function.entry_point() + i;
+            let invoc = match stmt {
+                GenStatement::Invocation(invoc) => invoc,
+                GenStatement::Return(_) => return false,
+            };
+            match address_zero_check(compilation_unit, function, pc, &is_caller) {
+                Some(non_zero_branch) => always_panics_from(
+                    function,
+                    compilation_unit.registry(),
+                    branch_target(&invoc.branches[non_zero_branch], pc),
+                ),
+                None => false,
+            }
+        })
+}
+
+/// Return the index of the branch taken when the address is not zero
+/// if the statement at pc is felt252_is_zero on the address converted to felt252
+/// or a match on the bool returned by ContractAddressZero::is_zero
+pub fn address_zero_check(
+    compilation_unit: &CompilationUnit,
+    function: &Function,
+    pc: usize,
+    is_address: &dyn Fn(&VarId) -> bool,
+) -> Option<usize> {
+    let invoc = match &function.get_statements()[pc - function.entry_point()] {
+        GenStatement::Invocation(invoc) => invoc,
+        GenStatement::Return(_) => return None,
+    };
+
+    match compilation_unit
+        .registry()
+        .get_libfunc(&invoc.libfunc_id)
+        .expect("Library function not found in the registry")
+    {
+        // The first branch is taken when the value is zero
+        CoreConcreteLibfunc::Felt252(Felt252Concrete::IsZero(_)) => {
+            let (conversion, _) = definition(compilation_unit, function, &invoc.args[0], pc)?;
+            match compilation_unit
+                .registry()
+                .get_libfunc(&conversion.libfunc_id)
+                .expect("Library function not found in the registry")
+            {
+                CoreConcreteLibfunc::StarkNet(
+                    StarkNetConcreteLibfunc::ContractAddressToFelt252(_),
+                ) if is_address(&conversion.args[0]) => Some(1),
+                _ => None,
+            }
+        }
+        CoreConcreteLibfunc::Enum(EnumConcreteLibfunc::Match(enum_match))
+            if enum_match.signature.param_signatures[0]
+                .ty
+                .debug_name
+                .as_deref()
+                == Some("core::bool") =>
+        {
+            bool_zero_check(compilation_unit, function, &invoc.args[0], pc, is_address)
+        }
+        _ => None,
+    }
+}
+
+/// Return the index of the branch taken when the address is not zero when matching the bool used at pc
+/// The first branch of a bool match is false
+fn bool_zero_check(
+    compilation_unit: &CompilationUnit,
+    function: &Function,
+    var: &VarId,
+    pc: usize,
+    is_address: &dyn Fn(&VarId) -> bool,
+) -> Option<usize> {
+    let (invoc, def_pc) = definition(compilation_unit, function, var, pc)?;
+
+    match compilation_unit
+        .registry()
+        .get_libfunc(&invoc.libfunc_id)
+        .expect("Library function not found in the registry")
+    {
+        CoreConcreteLibfunc::Bool(BoolConcreteLibfunc::Not(_)) => bool_zero_check(
+            compilation_unit,
+            function,
+            &invoc.args[0],
+            def_pc,
+            is_address,
+        )
+        .map(|non_zero_branch| 1 - non_zero_branch),
+        CoreConcreteLibfunc::FunctionCall(f_called) if invoc.args.iter().any(is_address) => {
+            match f_called.function.id.debug_name.as_deref() {
+                Some(name)
+                    if name.starts_with("core::")
+                        && name.ends_with("ContractAddressZero::is_zero") =>
+                {
+                    Some(0)
+                }
+                Some(name)
+                    if name.starts_with("core::")
+                        && name.ends_with("ContractAddressZero::is_non_zero") =>
+                {
+                    Some(1)
+                }
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+/// Return the statement defining the value of the variable used at pc and its pc
+fn definition<'a>(
+    compilation_unit: &CompilationUnit,
+    function: &'a Function,
+    var: &VarId,
+    pc: usize,
+) -> Option<(&'a Invocation, usize)> {
+    let def_pc = match function
+        .def_use(compilation_unit.registry())
+        .value_definitions(var, pc)
+        .as_slice()
+    {
+        [value] => match value.definition {
+            Definition::Statement(def_pc) => def_pc,
+            Definition::Parameter => return None,
+        },
+        _ => return None,
+    };
+    match &function.get_statements()[def_pc - function.entry_point()] {
+        GenStatement::Invocation(invoc) => Some((invoc, def_pc)),
+        GenStatement::Return(_) => None,
+    }
+}
@@ -0,0 +1,313 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use crate::core::function::{Function, Type};
+use cairo_lang_sierra::extensions::core::{CoreConcreteLibfunc, CoreLibfunc, CoreType};
+use cairo_lang_sierra::program::{GenStatement, Statement as SierraStatement};
+use cairo_lang_sierra::program_registry::ProgramRegistry;
+
+/// Whole program call graph of a compilation unit
+/// Nodes are identified by the function name
+#[derive(Debug, Clone, Default)]
+pub struct CallGraph {
+    /// Function name -> private/external/view/loop functions called
+    callees: HashMap<String, Vec<String>>,
+    /// Function name -> functions calling it
+    callers: HashMap<String, Vec<String>>,
+    /// Function name -> ABI functions called that do a call contract
+    external_calls: HashMap<String, Vec<String>>,
+    /// Function name -> ABI functions called that do a library call
+    library_calls: HashMap<String, Vec<String>>,
+    /// Functions that can be called from outside the contract
+    /// External - View - Constructor - L1Handler
+    entry_points: Vec<String>,
+    /// Strongly connected components
+    sccs: Vec<Vec<String>>,
+}
+
+impl CallGraph {
+    pub fn new(functions: &[Function], registry: &ProgramRegistry<CoreType, CoreLibfunc>) -> Self {
+        let mut callgraph = CallGraph::default();
+
+        for function in functions {
+            let caller = function.name();
+
+            for callee in function_calls(
+                function
+                    .private_functions_calls()
+                    .chain(function.loop_functions_calls()),
+                registry,
+            ) {
+                add_edge(&mut callgraph.callees, &caller, &callee);
+                add_edge(&mut callgraph.callers, &callee, &caller);
+            }
+            for callee in function_calls(function.external_functions_calls(), registry) {
+                add_edge(&mut callgraph.external_calls, &caller, &callee);
+                add_edge(&mut callgraph.callers, &callee, &caller);
+            }
+            for callee in function_calls(function.library_functions_calls(), registry) {
+                add_edge(&mut callgraph.library_calls, &caller, &callee);
+                add_edge(&mut callgraph.callers, &callee, &caller);
+            }
+
+            if matches!(
+                function.ty(),
+                Type::External | Type::View | Type::Constructor | Type::L1Handler
+            ) {
+                callgraph.entry_points.push(caller);
+            }
+        }
+
+        callgraph.compute_sccs(functions);
+
+        callgraph
+    }
+
+    /// Returns the private/external/view/loop functions called by the function
+    pub fn callees(&self, function_name: &str) -> impl Iterator<Item = &String> {
+        self.callees.get(function_name).into_iter().flatten()
+    }
+
+    /// Returns the functions calling the function
+    pub fn callers(&self, function_name: &str) -> impl Iterator<Item = &String> {
+        self.callers.get(function_name).into_iter().flatten()
+    }
+
+    /// Returns the ABI functions called by the function that do a call contract
+    pub fn external_calls(&self, function_name: &str) -> impl Iterator<Item = &String> {
+        self.external_calls.get(function_name).into_iter().flatten()
+    }
+
+    /// Returns the ABI functions called by the function that do a library call
+    pub fn library_calls(&self, function_name: &str) -> impl Iterator<Item = &String> {
+        self.library_calls.get(function_name).into_iter().flatten()
+    }
+
+    /// Returns the functions that can be called from outside the contract
+    pub fn entry_points(&self) -> impl Iterator<Item = &String> {
+        self.entry_points.iter()
+    }
+
+    /// Returns the function and all the functions transitively called by it
+    pub fn reachable_from(&self, function_name: &str) -> HashSet<String> {
+        self.reachable(std::iter::once(function_name.to_string()))
+    }
+
+    /// Returns all the functions transitively called by the entry points, entry points included
+    pub fn reachable_from_entry_points(&self) -> HashSet<String> {
+        self.reachable(self.entry_points.iter().cloned())
+    }
+
+    /// Returns the ABI functions doing a call contract transitively called by the function
+    pub fn reachable_external_calls(&self, function_name: &str) -> HashSet<String> {
+        self.reachable_from(function_name)
+            .iter()
+            .flat_map(|f| self.external_calls(f).cloned())
+            .collect()
+    }
+
+    /// Returns the ABI functions doing a library call transitively called by the function
+    pub fn reachable_library_calls(&self, function_name: &str) -> HashSet<String> {
+        self.reachable_from(function_name)
+            .iter()
+            .flat_map(|f| self.library_calls(f).cloned())
+            .collect()
+    }
+
+    /// Returns the strongly connected components of the call graph
+    pub fn sccs(&self) -> &Vec<Vec<String>> {
+        &self.sccs
+    }
+
+    /// Returns true if the function can call itself directly or through other functions
+    pub fn is_recursive(&self, function_name: &str) -> bool {
+        self.callees(function_name).any(|f| f == function_name)
+            || self
+                .sccs
+                .iter()
+                .any(|scc| scc.len() > 1 && scc.iter().any(|f| f == function_name))
+    }
+
+    fn reachable(&self, starts: impl Iterator<Item = String>) -> HashSet<String> {
+        let mut visited = HashSet::new();
+        let mut worklist: VecDeque<String> = starts.collect();
+
+        while let Some(function_name) = worklist.pop_front() {
+            if visited.contains(&function_name) {
+                continue;
+            }
+            worklist.extend(self.callees(&function_name).cloned());
+            visited.insert(function_name);
+        }
+
+        visited
+    }
+
+    /// Tarjan's algorithm
+    fn compute_sccs(&mut self, functions: &[Function]) {
+        let mut tarjan = Tarjan {
+            callgraph: self,
+            index: 0,
+            indexes: HashMap::new(),
+            lowlinks: HashMap::new(),
+            stack: Vec::new(),
+            on_stack: HashSet::new(),
+            sccs: Vec::new(),
+        };
+
+        for function in functions {
+            if !tarjan.indexes.contains_key(&function.name()) {
+                tarjan.strong_connect(function.name());
+            }
+        }
+
+        let sccs = tarjan.sccs;
+        self.sccs = sccs;
+    }
+}
+
+struct Tarjan<'a> {
+    callgraph: &'a CallGraph,
+    index: usize,
+    indexes: HashMap<String, usize>,
+    lowlinks: HashMap<String, usize>,
+    stack: Vec<String>,
+    on_stack: HashSet<String>,
+    sccs: Vec<Vec<String>>,
+}
+
+impl<'a> Tarjan<'a> {
+    fn strong_connect(&mut self, function_name: String) {
+        self.indexes.insert(function_name.clone(), self.index);
+        self.lowlinks.insert(function_name.clone(), self.index);
+        self.index += 1;
+        self.stack.push(function_name.clone());
+        self.on_stack.insert(function_name.clone());
+
+        let callgraph = self.callgraph;
+        for callee in callgraph.callees(&function_name) {
+            if !self.indexes.contains_key(callee) {
+                self.strong_connect(callee.clone());
+                let lowlink = self.lowlinks[&function_name].min(self.lowlinks[callee]);
+                self.lowlinks.insert(function_name.clone(), lowlink);
+            } else if self.on_stack.contains(callee) {
+                let lowlink = self.lowlinks[&function_name].min(self.indexes[callee]);
+                self.lowlinks.insert(function_name.clone(), lowlink);
+            }
+        }
+
+        // The function is the root of a strongly connected component
+        if self.lowlinks[&function_name] == self.indexes[&function_name] {
+            let mut scc = Vec::new();
+            while let Some(f) = self.stack.pop() {
+                self.on_stack.remove(&f);
+                let is_root = f == function_name;
+                scc.push(f);
+                if is_root {
+                    break;
+                }
+            }
+            self.sccs.push(scc);
+        }
+    }
+}
+
+/// Returns the name of the functions called by the statements
+fn function_calls<'a>(
+    statements: impl Iterator<Item = &'a SierraStatement>,
+    registry: &ProgramRegistry<CoreType, CoreLibfunc>,
+) -> Vec<String> {
+    statements
+        .filter_map(|s| {
+            if let GenStatement::Invocation(invoc) = s {
+                let lib_func = registry
+                    .get_libfunc(&invoc.libfunc_id)
+                    .expect("Library function not found in the registry");
+                if let CoreConcreteLibfunc::FunctionCall(f_called) = lib_func {
+                    return Some(
+                        f_called
+                            .function
+                            .id
+                            .debug_name
+                            .as_ref()
+                            .unwrap()
+                            .to_string(),
+                    );
+                }
+            }
+            None
+        })
+        .collect()
+}
+
+fn add_edge(edges: &mut HashMap<String, Vec<String>>, source: &str, destination: &str) {
+    let destinations = edges.entry(source.to_string()).or_default();
+    if !destinations.iter().any(|d| d == destination) {
+        destinations.push(destination.to_string());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::compilation_unit::CompilationUnit;
+    use std::collections::HashSet;
+
+    #[test]
+    fn recursive_strongly_connected_components() {
+        // a and b call each other, c calls itself and a, d doesn't call anything
+        let compilation_unit = CompilationUnit::from_sierra(
+            "
+            type felt252 = felt252;
+
+            libfunc function_call<user@test::a> = function_call<user@test::a>;
+            libfunc function_call<user@test::b> = function_call<user@test::b>;
+            libfunc function_call<user@test::c> = function_call<user@test::c>;
+
+            function_call<user@test::b>([0]) -> ([1]);
+            return([1]);
+            function_call<user@test::a>([0]) -> ([1]);
+            return([1]);
+            function_call<user@test::c>([0]) -> ([1]);
+            function_call<user@test::a>([1]) -> ([2]);
+            return([2]);
+            return([0]);
+
+            test::a@0([0]: felt252) -> (felt252);
+            test::b@2([0]: felt252) -> (felt252);
+            test::c@4([0]: felt252) -> (felt252);
+            test::d@7([0]: felt252) -> (felt252);
+            ",
+        );
+        let callgraph = compilation_unit.callgraph();
+
+        let sccs: HashSet<Vec<&str>> = callgraph
+            .sccs()
+            .iter()
+            .map(|scc| {
+                let mut scc: Vec<&str> = scc.iter().map(|f| f.as_str()).collect();
+                scc.sort_unstable();
+                scc
+            })
+            .collect();
+        assert_eq!(
+            sccs,
+            HashSet::from([vec!["test::a", "test::b"], vec!["test::c"], vec!["test::d"]])
+        );
+
+        assert!(callgraph.is_recursive("test::a"));
+        assert!(callgraph.is_recursive("test::b"));
+        assert!(callgraph.is_recursive("test::c"));
+        assert!(!callgraph.is_recursive("test::d"));
+
+        assert_eq!(
+            callgraph.reachable_from("test::c"),
+            HashSet::from([
+                "test::a".to_string(),
+                "test::b".to_string(),
+                "test::c".to_string()
+            ])
+        );
+        let mut callers: Vec<&String> = callgraph.callers("test::a").collect();
+        callers.sort_unstable();
+        assert_eq!(callers, ["test::b", "test::c"]);
+    }
+}
@@ -1,10 +1,12 @@
-use std::collections::{HashMap, VecDeque};
+use std::collections::{HashMap, HashSet, VecDeque};
 
-use crate::core::function::Function;
+use crate::core::function::{Function, Type};
 use crate::core::{basic_block::BasicBlock, cfg::Cfg, instruction::Instruction};
-use cairo_lang_sierra::extensions::core::{CoreLibfunc, CoreType};
+use cairo_lang_sierra::extensions::core::{CoreConcreteLibfunc, CoreLibfunc, CoreType};
+use cairo_lang_sierra::program::{GenStatement, Statement as SierraStatement};
 use cairo_lang_sierra::program_registry::ProgramRegistry;
 
+use super::callgraph::CallGraph;
 use super::traversal;
 
 pub trait Direction {
@@ -54,11 +56,14 @@ impl Direction for Forward {
 
         // Propagate
         for bb in basic_block.get_outgoing_basic_blocks() {
-            let changed = global_state
-                .get_mut(bb)
-                .unwrap()
-                .pre
-                .join(&current_state.pre);
+            let successor_state = &mut global_state.get_mut(bb).unwrap().pre;
+            // The basic blocks are numbered in the order of their statements
+            // going back to a previous one is the back edge of a loop
+            let changed = if *bb <= basic_block.get_id() {
+                successor_state.widen(&current_state.pre)
+            } else {
+                successor_state.join(&current_state.pre)
+            };
             if changed {
                 let basic_block = cfg.get_basic_block(*bb).unwrap().clone();
                 if !worklist.contains(&basic_block) {
@@ -99,11 +104,13 @@ impl Direction for Backward {
 
         // Propagate
         for bb in basic_block.get_incoming_basic_blocks() {
-            let changed = global_state
-                .get_mut(bb)
-                .unwrap()
-                .pre
-                .join(&current_state.post);
+            let predecessor_state = &mut global_state.get_mut(bb).unwrap().pre;
+            // Going back to a following basic block is the back edge of a loop
+            let changed = if *bb >= basic_block.get_id() {
+                predecessor_state.widen(&current_state.post)
+            } else {
+                predecessor_state.join(&current_state.post)
+            };
             if changed {
                 let basic_block = cfg.get_basic_block(*bb).unwrap().clone();
                 if !worklist.contains(&basic_block) {
@@ -122,6 +129,13 @@ pub trait Domain {
     /// Computes the least upper bound of two elements and store the result in self
     /// Return true if self changed
     fn join(&mut self, other: &Self) -> bool;
+    /// Computes an upper bound of two elements on the back edge of a loop and store the result in self
+    /// It must stop growing after a finite number of steps for the analysis to terminate
+    /// By default it's the join, enough when the domain has no infinite ascending chain
+    /// Return true if self changed
+    fn widen(&mut self, other: &Self) -> bool {
+        self.join(other)
+    }
 }
 
 pub trait Analysis {
@@ -229,3 +243,312 @@ where
         &self.state
     }
 }
+
+/// An analysis that can be run across function calls.
+/// Each function is summarized by the effect it has on the domain and the summary
+/// is applied at every call site of the function
+pub trait InterproceduralAnalysis: Analysis + Clone {
+    /// Compute the summary of a function from the state of its basic blocks.
+    /// By default for a forward analysis it's the join of the states at the end of the returning basic blocks
+    /// while for a backward analysis it's the state at the start of the entry basic block
+    fn summarize(
+        &self,
+        cfg: &dyn Cfg,
+        state: &HashMap<usize, AnalysisState<Self>>,
+    ) -> Self::Domain {
+        let mut summary = self.bottom_value();
+
+        if Self::Direction::IS_FORWARD {
+            for bb in cfg.get_basic_blocks().iter().filter(|bb| {
+                matches!(
+                    bb.last_instruction().map(|i| i.get_statement()),
+                    Some(SierraStatement::Return(_))
+                )
+            }) {
+                summary.join(&state[&bb.get_id()].post);
+            }
+        } else if let Some(entry_state) = state.get(&0) {
+            summary.join(&entry_state.pre);
+        }
+
+        summary
+    }
+
+    /// Merge a new summary of a function in the previous one and return true if it changed.
+    /// By default it's the join of the domain
+    fn join_summary(&self, summary: &mut Self::Domain, other: &Self::Domain) -> bool {
+        summary.join(other)
+    }
+
+    /// Apply the summary of the function called by the instruction to the state
+    fn apply_summary(
+        &self,
+        basic_block: &BasicBlock,
+        state: &mut Self::Domain,
+        instruction: &Instruction,
+        summary: &Self::Domain,
+    );
+}
+
+/// Wrapper used by the InterproceduralEngine to apply the summaries of the functions called
+/// after the transfer function of the underlying analysis
+struct Summarized<'s, A: InterproceduralAnalysis> {
+    analysis: &'s A,
+    summaries: &'s HashMap<String, A::Domain>,
+}
+
+impl<'s, A: InterproceduralAnalysis> Clone for Summarized<'s, A> {
+    fn clone(&self) -> Self {
+        Summarized {
+            analysis: self.analysis,
+            summaries: self.summaries,
+        }
+    }
+}
+
+impl<'s, A: InterproceduralAnalysis> Analysis for Summarized<'s, A> {
+    type Domain = A::Domain;
+    type Direction = A::Direction;
+
+    fn transfer_function(
+        &self,
+        basic_block: &BasicBlock,
+        state: &mut Self::Domain,
+        instruction: &Instruction,
+        functions: &[Function],
+        registry: &ProgramRegistry<CoreType, CoreLibfunc>,
+    ) {
+        self.analysis
+            .transfer_function(basic_block, state, instruction, functions, registry);
+
+        if let GenStatement::Invocation(invoc) = instruction.get_statement() {
+            let lib_func = registry
+                .get_libfunc(&invoc.libfunc_id)
+                .expect("Library function not found in the registry");
+            if let CoreConcreteLibfunc::FunctionCall(f_called) = lib_func {
+                if let Some(summary) = self
+                    .summaries
+                    .get(f_called.function.id.debug_name.as_ref().unwrap().as_str())
+                {
+                    self.analysis
+                        .apply_summary(basic_block, state, instruction, summary);
+                }
+            }
+        }
+    }
+
+    fn bottom_value(&self) -> Self::Domain {
+        self.analysis.bottom_value()
+    }
+}
+
+/// Engine to solve data flow problems across function calls.
+/// The user defined functions are analyzed with the intraprocedural Engine
+/// and their summaries are computed until a fixpoint is reached over the call graph
+pub struct InterproceduralEngine<'a, A: InterproceduralAnalysis> {
+    functions: &'a [Function],
+    analysis: A,
+    /// Function name to summary
+    summaries: HashMap<String, A::Domain>,
+    /// Function name to the state of each basic block
+    state: HashMap<String, HashMap<usize, AnalysisState<A>>>,
+}
+
+impl<'a, A> InterproceduralEngine<'a, A>
+where
+    A: InterproceduralAnalysis,
+{
+    /// Create a new InterproceduralEngine to solve the analysis on the functions
+    pub fn new(functions: &'a [Function], analysis: A) -> Self {
+        InterproceduralEngine {
+            functions,
+            analysis,
+            summaries: HashMap::new(),
+            state: HashMap::new(),
+        }
+    }
+
+    /// Run the analysis on every user defined function until the summaries don't change anymore
+    pub fn run_analysis(&mut self, registry: &ProgramRegistry<CoreType, CoreLibfunc>) {
+        let callgraph = CallGraph::new(self.functions, registry);
+
+        let mut worklist: VecDeque<&Function> = self
+            .functions
+            .iter()
+            .filter(|f| is_summarizable(f))
+            .collect();
+
+        while let Some(function) = worklist.pop_front() {
+            let state: HashMap<usize, AnalysisState<A>> = {
+                let mut engine = Engine::new(
+                    function.get_cfg(),
+                    Summarized {
+                        analysis: &self.analysis,
+                        summaries: &self.summaries,
+                    },
+                );
+                engine.run_analysis(self.functions, registry);
+                engine
+                    .result()
+                    .iter()
+                    .map(|(id, bb_state)| {
+                        (
+                            *id,
+                            AnalysisState {
+                                pre: bb_state.pre.clone(),
+                                post: bb_state.post.clone(),
+                            },
+                        )
+                    })
+                    .collect()
+            };
+
+            let summary = self.analysis.summarize(function.get_cfg(), &state);
+            let changed = self.analysis.join_summary(
+                self.summaries
+                    .entry(function.name())
+                    .or_insert_with(|| self.analysis.bottom_value()),
+                &summary,
+            );
+            self.state.insert(function.name(), state);
+
+            // The summary changed, the functions calling it must be analyzed again
+            if changed {
+                let calling_functions: HashSet<String> =
+                    callgraph.callers(&function.name()).cloned().collect();
+                for calling_function in self
+                    .functions
+                    .iter()
+                    .filter(|f| is_summarizable(f) && calling_functions.contains(&f.name()))
+                {
+                    if !worklist.iter().any(|f| f.name() == calling_function.name()) {
+                        worklist.push_back(calling_function);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Return the summary of a function after run_analysis was called
+    pub fn summary(&self, function_name: &str) -> Option<&A::Domain> {
+        self.summaries.get(function_name)
+    }
+
+    /// Return the result of the analysis for a function after run_analysis was called
+    pub fn result(&self, function_name: &str) -> Option<&HashMap<usize, AnalysisState<A>>> {
+        self.state.get(function_name)
+    }
+}
+
+/// Return true if the function is user defined and can be summarized
+fn is_summarizable(function: &Function) -> bool {
+    matches!(
+        function.ty(),
+        Type::External
+            | Type::View
+            | Type::Private
+            | Type::Constructor
+            | Type::L1Handler
+            | Type::Loop
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::compilation_unit::CompilationUnit;
+
+    /// Functions called directly or through other functions
+    #[derive(Clone, Debug, Default)]
+    struct Called(HashSet<String>);
+
+    impl Domain for Called {
+        fn top() -> Self {
+            Called::default()
+        }
+
+        fn bottom() -> Self {
+            Called::default()
+        }
+
+        fn join(&mut self, other: &Self) -> bool {
+            let len = self.0.len();
+            self.0.extend(other.0.iter().cloned());
+            self.0.len() != len
+        }
+    }
+
+    #[derive(Clone)]
+    struct CalledAnalysis;
+
+    impl Analysis for CalledAnalysis {
+        type Domain = Called;
+        type Direction = Forward;
+
+        fn transfer_function(
+            &self,
+            _basic_block: &BasicBlock,
+            state: &mut Self::Domain,
+            instruction: &Instruction,
+            _functions: &[Function],
+            registry: &ProgramRegistry<CoreType, CoreLibfunc>,
+        ) {
+            if let GenStatement::Invocation(invoc) = instruction.get_statement() {
+                if let CoreConcreteLibfunc::FunctionCall(f_called) = registry
+                    .get_libfunc(&invoc.libfunc_id)
+                    .expect("Library function not found in the registry")
+                {
+                    state.0.insert(f_called.function.id.to_string());
+                }
+            }
+        }
+
+        fn bottom_value(&self) -> Self::Domain {
+            Called::default()
+        }
+    }
+
+    impl InterproceduralAnalysis for CalledAnalysis {
+        fn apply_summary(
+            &self,
+            _basic_block: &BasicBlock,
+            state: &mut Self::Domain,
+            _instruction: &Instruction,
+            summary: &Self::Domain,
+        ) {
+            state.join(summary);
+        }
+    }
+
+    #[test]
+    fn summaries_of_recursive_functions() {
+        // a and b call each other, c calls a
+        let compilation_unit = CompilationUnit::from_sierra(
+            "
+            type felt252 = felt252;
+
+            libfunc function_call<user@test::a> = function_call<user@test::a>;
+            libfunc function_call<user@test::b> = function_call<user@test::b>;
+
+            function_call<user@test::b>([0]) -> ([1]);
+            return([1]);
+            function_call<user@test::a>([0]) -> ([1]);
+            return([1]);
+            function_call<user@test::a>([0]) -> ([1]);
+            return([1]);
+
+            test::a@0([0]: felt252) -> (felt252);
+            test::b@2([0]: felt252) -> (felt252);
+            test::c@4([0]: felt252) -> (felt252);
+            ",
+        );
+        let functions: Vec<Function> = compilation_unit.functions().cloned().collect();
+        let mut engine = InterproceduralEngine::new(&functions, CalledAnalysis);
+        engine.run_analysis(compilation_unit.registry());
+
+        let recursive = HashSet::from(["test::a".to_string(), "test::b".to_string()]);
+        for function in ["test::a", "test::b", "test::c"] {
+            assert_eq!(engine.summary(function).unwrap().0, recursive);
+        }
+    }
+}
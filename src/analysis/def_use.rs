@@ -0,0 +1,449 @@
+use std::collections::{BTreeSet, HashMap, HashSet, VecDeque};
+
+use crate::core::function::Function;
+use crate::utils::branch_target;
+use cairo_lang_sierra::extensions::core::{CoreConcreteLibfunc, CoreLibfunc, CoreType};
+use cairo_lang_sierra::extensions::mem::MemConcreteLibfunc;
+use cairo_lang_sierra::extensions::structure::StructConcreteLibfunc;
+use cairo_lang_sierra::ids::VarId;
+use cairo_lang_sierra::program::GenStatement;
+use cairo_lang_sierra::program_registry::ProgramRegistry;
+
+/// Where a variable is defined
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Definition {
+    /// Parameter of the function
+    Parameter,
+    /// Result of the statement at pc
+    Statement(usize),
+}
+
+/// A value of the function, a variable at one of its definitions
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Value {
+    pub var: VarId,
+    pub definition: Definition,
+}
+
+impl Value {
+    pub fn new(var: VarId, definition: Definition) -> Self {
+        Value { var, definition }
+    }
+}
+
+/// Def-use and use-def chains of the variables of a function
+/// Sierra can reuse the same variable id in different branches (e.g. store_temp([0]) -> ([0]))
+/// so a variable can have more than one definition, the values are identified by the variable and its definition
+#[derive(Debug, Clone, Default)]
+pub struct DefUse {
+    /// Variable -> where it's defined
+    definitions: HashMap<VarId, Vec<Definition>>,
+    /// Variable -> pc of the statements using it
+    uses: HashMap<VarId, Vec<usize>>,
+    /// (Variable, pc) -> definitions of the variable reaching the statement at pc
+    /// only for the variables with more than one definition
+    reaching_definitions: HashMap<(VarId, usize), BTreeSet<Definition>>,
+    /// Value -> index of its alias class
+    alias_class: HashMap<Value, usize>,
+    /// Values that are the same because of dup, store_temp, store_local, rename
+    /// or because they are a member of a struct constructed and then deconstructed
+    alias_classes: Vec<Vec<Value>>,
+    /// Values defined by a statement forwarding another value (copy or deconstruction of a known struct)
+    forwarded: HashSet<Value>,
+    /// Pc of the statements that only copy a value (dup, store_temp, store_local, rename)
+    copies: HashSet<usize>,
+}
+
+impl DefUse {
+    pub fn new(function: &Function, registry: &ProgramRegistry<CoreType, CoreLibfunc>) -> Self {
+        let mut def_use = DefUse::default();
+        let entry_point = function.entry_point();
+        let statements = function.get_statements();
+
+        for param in function.params_all() {
+            def_use.add_definition(&param.id, Definition::Parameter);
+        }
+        for (i, stmt) in statements.iter().enumerate() {
+            let pc = entry_point + i;
+            match stmt {
+                GenStatement::Invocation(invoc) => {
+                    for arg in invoc.args.iter() {
+                        def_use.add_use(arg, pc);
+                    }
+                    for var in invoc.branches.iter().flat_map(|b| b.results.iter()) {
+                        def_use.add_definition(var, Definition::Statement(pc));
+                    }
+                }
+                GenStatement::Return(vars) => {
+                    for var in vars.iter() {
+                        def_use.add_use(var, pc);
+                    }
+                }
+            }
+        }
+        def_use.compute_reaching_definitions(function);
+
+        let mut aliases = UnionFind::default();
+        for (var, definitions) in def_use.definitions.iter() {
+            for definition in definitions {
+                aliases.insert(Value::new(var.clone(), *definition));
+            }
+        }
+
+        // Copies, the value copied is the last argument, store_local also takes the local to initialize
+        let mut deconstructs = Vec::new();
+        for (i, stmt) in statements.iter().enumerate() {
+            let pc = entry_point + i;
+            let invoc = match stmt {
+                GenStatement::Invocation(invoc) => invoc,
+                GenStatement::Return(_) => continue,
+            };
+            match registry
+                .get_libfunc(&invoc.libfunc_id)
+                .expect("Library function not found in the registry")
+            {
+                CoreConcreteLibfunc::Dup(_)
+                | CoreConcreteLibfunc::Mem(
+                    MemConcreteLibfunc::StoreTemp(_)
+                    | MemConcreteLibfunc::StoreLocal(_)
+                    | MemConcreteLibfunc::Rename(_),
+                ) => {
+                    let copied = match invoc.args.last() {
+                        Some(copied) => copied,
+                        None => continue,
+                    };
+                    def_use.copies.insert(pc);
+                    for var in invoc.branches.iter().flat_map(|b| b.results.iter()) {
+                        let value = Value::new(var.clone(), Definition::Statement(pc));
+                        for definition in def_use.reaching_definitions(copied, pc) {
+                            aliases.union(&value, &Value::new(copied.clone(), definition));
+                        }
+                        def_use.forwarded.insert(value);
+                    }
+                }
+                CoreConcreteLibfunc::Struct(StructConcreteLibfunc::Deconstruct(_)) => {
+                    deconstructs.push(pc);
+                }
+                _ => (),
+            }
+        }
+
+        // The members of a struct deconstructed are the values used to construct it
+        // repeat until nothing changes to handle nested structs
+        let constructs: Vec<Value> = def_use
+            .definitions
+            .iter()
+            .flat_map(|(var, definitions)| {
+                definitions
+                    .iter()
+                    .map(move |definition| Value::new(var.clone(), *definition))
+            })
+            .filter(|value| match value.definition {
+                Definition::Statement(pc) => is_construct(function, registry, pc),
+                Definition::Parameter => false,
+            })
+            .collect();
+        let mut changed = true;
+        while changed {
+            changed = false;
+            for pc in deconstructs.iter().copied() {
+                let invoc = match &statements[pc - entry_point] {
+                    GenStatement::Invocation(invoc) => invoc,
+                    GenStatement::Return(_) => continue,
+                };
+                let roots: Vec<Value> = def_use
+                    .reaching_definitions(&invoc.args[0], pc)
+                    .into_iter()
+                    .map(|definition| aliases.find(&Value::new(invoc.args[0].clone(), definition)))
+                    .collect();
+                let construct_pcs: Vec<usize> = constructs
+                    .iter()
+                    .filter(|construct| roots.contains(&aliases.find(construct)))
+                    .filter_map(|construct| match construct.definition {
+                        Definition::Statement(construct_pc) => Some(construct_pc),
+                        Definition::Parameter => None,
+                    })
+                    .collect();
+
+                for construct_pc in construct_pcs {
+                    let members = match &statements[construct_pc - entry_point] {
+                        GenStatement::Invocation(construct) => &construct.args,
+                        GenStatement::Return(_) => continue,
+                    };
+                    for (result, member) in invoc.branches[0].results.iter().zip(members) {
+                        let value = Value::new(result.clone(), Definition::Statement(pc));
+                        for definition in def_use.reaching_definitions(member, construct_pc) {
+                            changed |=
+                                aliases.union(&value, &Value::new(member.clone(), definition));
+                        }
+                        def_use.forwarded.insert(value);
+                    }
+                }
+            }
+        }
+
+        (def_use.alias_class, def_use.alias_classes) = aliases.classes();
+
+        def_use
+    }
+
+    /// Return where the variable is defined
+    pub fn definitions(&self, var: &VarId) -> &[Definition] {
+        self.definitions.get(var).map_or(&[], |d| d.as_slice())
+    }
+
+    /// Return the pc of the statements using the variable
+    pub fn uses(&self, var: &VarId) -> &[usize] {
+        self.uses.get(var).map_or(&[], |u| u.as_slice())
+    }
+
+    /// Return the definitions of the variable that reach the statement at pc
+    pub fn reaching_definitions(&self, var: &VarId, pc: usize) -> Vec<Definition> {
+        match self.reaching_definitions.get(&(var.clone(), pc)) {
+            Some(definitions) => definitions.iter().copied().collect(),
+            None => self.definitions(var).to_vec(),
+        }
+    }
+
+    /// Return the values that are the same as the value, the value included
+    pub fn aliases(&self, value: &Value) -> &[Value] {
+        self.alias_class
+            .get(value)
+            .map_or(&[], |class| self.alias_classes[*class].as_slice())
+    }
+
+    /// Return true if the two values are the same
+    pub fn are_aliases(&self, a: &Value, b: &Value) -> bool {
+        a == b
+            || matches!(
+                (self.alias_class.get(a), self.alias_class.get(b)),
+                (Some(class_a), Some(class_b)) if class_a == class_b
+            )
+    }
+
+    /// Return true if the statement at pc only copies a value (dup, store_temp, store_local, rename)
+    pub fn is_copy(&self, pc: usize) -> bool {
+        self.copies.contains(&pc)
+    }
+
+    /// Return the pc of the statements using the value through any of its aliases
+    /// The statements only copying the value are not included
+    pub fn value_uses(&self, value: &Value) -> Vec<usize> {
+        let mut uses: Vec<usize> = self
+            .aliases(value)
+            .iter()
+            .flat_map(|alias| {
+                self.uses(&alias.var).iter().copied().filter(|pc| {
+                    self.reaching_definitions(&alias.var, *pc)
+                        .contains(&alias.definition)
+                })
+            })
+            .filter(|pc| !self.is_copy(*pc))
+            .collect();
+        uses.sort_unstable();
+        uses.dedup();
+        uses
+    }
+
+    /// Return where the values held by the variable used at pc were originally defined
+    /// i.e. the definitions of their aliases that don't forward another value
+    pub fn value_definitions(&self, var: &VarId, pc: usize) -> Vec<Value> {
+        let mut definitions: Vec<Value> = self
+            .reaching_definitions(var, pc)
+            .into_iter()
+            .flat_map(|definition| self.aliases(&Value::new(var.clone(), definition)))
+            .filter(|value| !self.forwarded.contains(value))
+            .cloned()
+            .collect();
+        definitions.sort_unstable_by_key(|value| (value.definition, value.var.id));
+        definitions.dedup();
+        definitions
+    }
+
+    fn add_definition(&mut self, var: &VarId, definition: Definition) {
+        let definitions = self.definitions.entry(var.clone()).or_default();
+        if !definitions.contains(&definition) {
+            definitions.push(definition);
+        }
+    }
+
+    fn add_use(&mut self, var: &VarId, pc: usize) {
+        let uses = self.uses.entry(var.clone()).or_default();
+        if !uses.contains(&pc) {
+            uses.push(pc);
+        }
+    }
+
+    /// Compute which definitions reach each use of the variables defined more than once
+    fn compute_reaching_definitions(&mut self, function: &Function) {
+        let redefined: HashSet<VarId> = self
+            .definitions
+            .iter()
+            .filter(|(_, definitions)| definitions.len() > 1)
+            .map(|(var, _)| var.clone())
+            .collect();
+        if redefined.is_empty() {
+            return;
+        }
+
+        let entry_point = function.entry_point();
+        let statements = function.get_statements();
+        let mut state: HashMap<usize, HashMap<VarId, BTreeSet<Definition>>> = HashMap::new();
+        let entry_state: HashMap<VarId, BTreeSet<Definition>> = function
+            .params_all()
+            .filter(|param| redefined.contains(&param.id))
+            .map(|param| (param.id.clone(), BTreeSet::from([Definition::Parameter])))
+            .collect();
+        state.insert(entry_point, entry_state);
+
+        let mut worklist = VecDeque::from([entry_point]);
+        while let Some(pc) = worklist.pop_front() {
+            let current = state[&pc].clone();
+            let invoc = match statements.get(pc - entry_point) {
+                Some(GenStatement::Invocation(invoc)) => invoc,
+                _ => continue,
+            };
+            for branch in invoc.branches.iter() {
+                let mut next = current.clone();
+                for var in branch.results.iter().filter(|var| redefined.contains(var)) {
+                    next.insert(var.clone(), BTreeSet::from([Definition::Statement(pc)]));
+                }
+
+                let target = branch_target(branch, pc);
+                let mut changed = !state.contains_key(&target);
+                let target_state = state.entry(target).or_default();
+                for (var, definitions) in next {
+                    let target_definitions = target_state.entry(var).or_default();
+                    for definition in definitions {
+                        changed |= target_definitions.insert(definition);
+                    }
+                }
+                if changed && !worklist.contains(&target) {
+                    worklist.push_back(target);
+                }
+            }
+        }
+
+        for var in redefined {
+            for pc in self.uses(&var).to_vec() {
+                let definitions = state
+                    .get(&pc)
+                    .and_then(|state| state.get(&var))
+                    .cloned()
+                    .unwrap_or_default();
+                self.reaching_definitions
+                    .insert((var.clone(), pc), definitions);
+            }
+        }
+    }
+}
+
+/// Return the values defined by the results of the statement at pc
+pub fn results_at<'a>(
+    results: impl IntoIterator<Item = &'a VarId> + 'a,
+    pc: usize,
+) -> impl Iterator<Item = Value> + 'a {
+    results
+        .into_iter()
+        .map(move |var| Value::new(var.clone(), Definition::Statement(pc)))
+}
+
+/// Return true if the value is only dropped
+pub fn is_dropped(
+    function: &Function,
+    registry: &ProgramRegistry<CoreType, CoreLibfunc>,
+    value: &Value,
+) -> bool {
+    function
+        .def_use(registry)
+        .value_uses(value)
+        .into_iter()
+        .all(
+            |pc| match &function.get_statements()[pc - function.entry_point()] {
+                GenStatement::Invocation(invoc) => matches!(
+                    registry
+                        .get_libfunc(&invoc.libfunc_id)
+                        .expect("Library function not found in the registry"),
+                    CoreConcreteLibfunc::Drop(_)
+                ),
+                GenStatement::Return(_) => false,
+            },
+        )
+}
+
+/// Return true if the statement at pc constructs a struct
+fn is_construct(
+    function: &Function,
+    registry: &ProgramRegistry<CoreType, CoreLibfunc>,
+    pc: usize,
+) -> bool {
+    match &function.get_statements()[pc - function.entry_point()] {
+        GenStatement::Invocation(invoc) => matches!(
+            registry
+                .get_libfunc(&invoc.libfunc_id)
+                .expect("Library function not found in the registry"),
+            CoreConcreteLibfunc::Struct(StructConcreteLibfunc::Construct(_))
+        ),
+        GenStatement::Return(_) => false,
+    }
+}
+
+#[derive(Default)]
+struct UnionFind {
+    parents: HashMap<Value, Value>,
+}
+
+impl UnionFind {
+    fn insert(&mut self, value: Value) {
+        self.parents
+            .entry(value.clone())
+            .or_insert_with(|| value.clone());
+    }
+
+    fn find(&mut self, value: &Value) -> Value {
+        let parent = match self.parents.get(value) {
+            Some(parent) => parent.clone(),
+            None => return value.clone(),
+        };
+        if parent == *value {
+            return parent;
+        }
+        let root = self.find(&parent);
+        self.parents.insert(value.clone(), root.clone());
+        root
+    }
+
+    /// Merge the classes of the two values and return true if they were different
+    fn union(&mut self, a: &Value, b: &Value) -> bool {
+        self.insert(a.clone());
+        self.insert(b.clone());
+        let root_a = self.find(a);
+        let root_b = self.find(b);
+        if root_a != root_b {
+            self.parents.insert(root_b, root_a);
+            return true;
+        }
+        false
+    }
+
+    /// Return the value -> class index map and the classes
+    fn classes(mut self) -> (HashMap<Value, usize>, Vec<Vec<Value>>) {
+        let mut values: Vec<Value> = self.parents.keys().cloned().collect();
+        values.sort_unstable_by_key(|value| (value.var.id, value.definition));
+
+        let mut roots: HashMap<Value, usize> = HashMap::new();
+        let mut alias_class = HashMap::new();
+        let mut alias_classes: Vec<Vec<Value>> = Vec::new();
+        for value in values {
+            let root = self.find(&value);
+            let class = *roots.entry(root).or_insert_with(|| {
+                alias_classes.push(Vec::new());
+                alias_classes.len() - 1
+            });
+            alias_classes[class].push(value.clone());
+            alias_class.insert(value, class);
+        }
+
+        (alias_class, alias_classes)
+    }
+}
@@ -0,0 +1,296 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::core::cfg::Cfg;
+
+/// Id of the virtual node used as root when the graph has multiple roots
+/// e.g. the exit nodes when computing the post-dominators
+const VIRTUAL_ROOT: usize = usize::MAX;
+
+/// Dominator tree and dominance frontiers of a CFG
+/// When computed on the reversed CFG it represents the post-dominator tree and the post-dominance frontiers
+#[derive(Debug, Clone, Default)]
+pub struct Dominators {
+    /// Basic block id -> immediate dominator id
+    /// The root(s) and the unreachable basic blocks are not present
+    immediate_dominators: HashMap<usize, usize>,
+    /// Basic blocks reachable from the root(s)
+    reachable: HashSet<usize>,
+    /// Basic block id -> dominance frontier
+    dominance_frontiers: HashMap<usize, HashSet<usize>>,
+}
+
+impl Dominators {
+    /// Compute the dominators of the CFG, the entry basic block is the first one
+    pub fn new(cfg: &dyn Cfg) -> Self {
+        if cfg.get_basic_blocks().is_empty() {
+            return Dominators::default();
+        }
+
+        let successors = |id: usize| -> Vec<usize> {
+            cfg.get_basic_block(id)
+                .map(|bb| bb.get_outgoing_basic_blocks().clone())
+                .unwrap_or_default()
+        };
+        let predecessors = |id: usize| -> Vec<usize> {
+            cfg.get_basic_block(id)
+                .map(|bb| bb.get_incoming_basic_blocks().clone())
+                .unwrap_or_default()
+        };
+
+        Dominators::compute(
+            vec![cfg.get_basic_blocks()[0].get_id()],
+            successors,
+            predecessors,
+        )
+    }
+
+    /// Compute the post-dominators of the CFG, the exit basic blocks are the ones without outgoing edges
+    pub fn new_post_dominators(cfg: &dyn Cfg) -> Self {
+        let exits: Vec<usize> = cfg
+            .get_basic_blocks()
+            .iter()
+            .filter(|bb| bb.get_outgoing_basic_blocks().is_empty())
+            .map(|bb| bb.get_id())
+            .collect();
+
+        if exits.is_empty() {
+            return Dominators::default();
+        }
+
+        // The edges are reversed
+        let successors = |id: usize| -> Vec<usize> {
+            cfg.get_basic_block(id)
+                .map(|bb| bb.get_incoming_basic_blocks().clone())
+                .unwrap_or_default()
+        };
+        let predecessors = |id: usize| -> Vec<usize> {
+            cfg.get_basic_block(id)
+                .map(|bb| bb.get_outgoing_basic_blocks().clone())
+                .unwrap_or_default()
+        };
+
+        Dominators::compute(exits, successors, predecessors)
+    }
+
+    /// Return the immediate dominator of the basic block
+    /// None if it's the root or it's unreachable
+    pub fn immediate_dominator(&self, basic_block: usize) -> Option<usize> {
+        self.immediate_dominators
+            .get(&basic_block)
+            .copied()
+            .filter(|idom| *idom != VIRTUAL_ROOT)
+    }
+
+    /// Return the basic blocks immediately dominated by the basic block
+    pub fn children(&self, basic_block: usize) -> Vec<usize> {
+        let mut children: Vec<usize> = self
+            .immediate_dominators
+            .iter()
+            .filter(|(_, idom)| **idom == basic_block)
+            .map(|(bb, _)| *bb)
+            .collect();
+        children.sort_unstable();
+        children
+    }
+
+    /// Return the basic blocks dominating the basic block, starting from the basic block itself up to the root
+    pub fn dominators(&self, basic_block: usize) -> Vec<usize> {
+        let mut dominators = Vec::new();
+        if !self.reachable.contains(&basic_block) {
+            return dominators;
+        }
+
+        let mut current = basic_block;
+        dominators.push(current);
+        while let Some(idom) = self.immediate_dominator(current) {
+            dominators.push(idom);
+            current = idom;
+        }
+
+        dominators
+    }
+
+    /// Return true if every path from the root to b goes through a
+    /// A basic block dominates itself
+    pub fn dominates(&self, a: usize, b: usize) -> bool {
+        self.dominators(b).contains(&a)
+    }
+
+    /// Return true if a dominates b and a is different from b
+    pub fn strictly_dominates(&self, a: usize, b: usize) -> bool {
+        a != b && self.dominates(a, b)
+    }
+
+    /// Return the dominance frontier of the basic block
+    /// i.e. the basic blocks where the dominance of the basic block stops
+    pub fn dominance_frontier(&self, basic_block: usize) -> HashSet<usize> {
+        self.dominance_frontiers
+            .get(&basic_block)
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    /// Cooper, Harvey, Kennedy "A Simple, Fast Dominance Algorithm"
+    fn compute(
+        roots: Vec<usize>,
+        successors: impl Fn(usize) -> Vec<usize>,
+        predecessors: impl Fn(usize) -> Vec<usize>,
+    ) -> Self {
+        // With multiple roots we add a virtual root having the roots as successors
+        let root = if roots.len() == 1 {
+            roots[0]
+        } else {
+            VIRTUAL_ROOT
+        };
+        let successors = |id: usize| -> Vec<usize> {
+            if id == VIRTUAL_ROOT {
+                roots.clone()
+            } else {
+                successors(id)
+            }
+        };
+        let predecessors = |id: usize| -> Vec<usize> {
+            let mut preds = predecessors(id);
+            if root == VIRTUAL_ROOT && roots.contains(&id) {
+                preds.push(VIRTUAL_ROOT);
+            }
+            preds
+        };
+
+        // Postorder from the root
+        let mut postorder = Vec::new();
+        let mut visited = HashSet::new();
+        let mut stack = vec![(root, successors(root), 0)];
+        visited.insert(root);
+        while let Some((node, node_successors, next)) = stack.last_mut() {
+            if let Some(successor) = node_successors.get(*next).copied() {
+                *next += 1;
+                if visited.insert(successor) {
+                    stack.push((successor, successors(successor), 0));
+                }
+            } else {
+                postorder.push(*node);
+                stack.pop();
+            }
+        }
+        let postorder_number: HashMap<usize, usize> =
+            postorder.iter().enumerate().map(|(i, n)| (*n, i)).collect();
+
+        let intersect = |idoms: &HashMap<usize, usize>, mut a: usize, mut b: usize| -> usize {
+            while a != b {
+                while postorder_number[&a] < postorder_number[&b] {
+                    a = idoms[&a];
+                }
+                while postorder_number[&b] < postorder_number[&a] {
+                    b = idoms[&b];
+                }
+            }
+            a
+        };
+
+        let mut idoms: HashMap<usize, usize> = HashMap::new();
+        idoms.insert(root, root);
+        let mut changed = true;
+        while changed {
+            changed = false;
+            // Reverse postorder without the root
+            for node in postorder.iter().rev().skip(1) {
+                let mut new_idom = None;
+                for pred in predecessors(*node)
+                    .into_iter()
+                    .filter(|p| idoms.contains_key(p))
+                {
+                    new_idom = match new_idom {
+                        None => Some(pred),
+                        Some(current) => Some(intersect(&idoms, pred, current)),
+                    };
+                }
+                if let Some(new_idom) = new_idom {
+                    if idoms.get(node) != Some(&new_idom) {
+                        idoms.insert(*node, new_idom);
+                        changed = true;
+                    }
+                }
+            }
+        }
+
+        // Dominance frontiers
+        let mut dominance_frontiers: HashMap<usize, HashSet<usize>> = HashMap::new();
+        for node in postorder.iter() {
+            let preds: Vec<usize> = predecessors(*node)
+                .into_iter()
+                .filter(|p| idoms.contains_key(p))
+                .collect();
+            if preds.len() < 2 {
+                continue;
+            }
+            for pred in preds {
+                let mut runner = pred;
+                while runner != idoms[node] {
+                    dominance_frontiers.entry(runner).or_default().insert(*node);
+                    runner = idoms[&runner];
+                }
+            }
+        }
+        dominance_frontiers.remove(&VIRTUAL_ROOT);
+
+        idoms.remove(&root);
+        let mut reachable: HashSet<usize> = postorder.into_iter().collect();
+        reachable.remove(&VIRTUAL_ROOT);
+
+        Dominators {
+            immediate_dominators: idoms,
+            reachable,
+            dominance_frontiers,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::cfg::CfgRegular;
+    use cairo_lang_sierra::extensions::core::{CoreLibfunc, CoreType};
+    use cairo_lang_sierra::program::GenStatement;
+    use cairo_lang_sierra::program_registry::ProgramRegistry;
+    use cairo_lang_sierra::ProgramParser;
+
+    #[test]
+    fn irreducible_loop() {
+        // Sierra doesn't allow to jump backwards, the loop between 1 and 3 can be entered from both of them
+        // 0 -> 1 -> 2 -> 3 -> 4
+        // 0 -> 3 -> 1
+        // 1 -> 5
+        let program = ProgramParser::new().parse("").unwrap();
+        let registry = ProgramRegistry::<CoreType, CoreLibfunc>::new(&program).unwrap();
+        let cfg = CfgRegular::from_basic_blocks(
+            vec![vec![GenStatement::Return(vec![])]; 6],
+            &[(0, 1), (0, 3), (1, 2), (1, 5), (2, 3), (3, 1), (3, 4)],
+            &registry,
+        );
+
+        let dominators = Dominators::new(&cfg);
+        assert_eq!(dominators.immediate_dominator(0), None);
+        assert_eq!(dominators.immediate_dominator(1), Some(0));
+        assert_eq!(dominators.immediate_dominator(2), Some(1));
+        assert_eq!(dominators.immediate_dominator(3), Some(0));
+        assert_eq!(dominators.immediate_dominator(4), Some(3));
+        assert_eq!(dominators.immediate_dominator(5), Some(1));
+        assert!(!dominators.dominates(1, 3));
+        assert!(!dominators.dominates(3, 1));
+        assert_eq!(dominators.children(0), vec![1, 3]);
+        assert_eq!(dominators.dominators(2), vec![2, 1, 0]);
+        assert_eq!(dominators.dominance_frontier(1), HashSet::from([3]));
+        assert_eq!(dominators.dominance_frontier(2), HashSet::from([3]));
+        assert_eq!(dominators.dominance_frontier(3), HashSet::from([1]));
+
+        // The exits 4 and 5 are joined by a virtual root
+        let post_dominators = Dominators::new_post_dominators(&cfg);
+        assert_eq!(post_dominators.immediate_dominator(2), Some(3));
+        assert_eq!(post_dominators.immediate_dominator(0), None);
+        assert_eq!(post_dominators.immediate_dominator(4), None);
+        assert!(!post_dominators.dominates(1, 0));
+        assert!(!post_dominators.dominates(3, 0));
+        assert_eq!(post_dominators.dominance_frontier(2), HashSet::from([1]));
+    }
+}
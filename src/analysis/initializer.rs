@@ -0,0 +1,184 @@
+use std::collections::HashSet;
+
+use super::panics::{always_panics_from, is_panic_block};
+use super::taint::WrapperVariable;
+use crate::core::cfg::Cfg;
+use crate::core::compilation_unit::CompilationUnit;
+use crate::core::function::{Function, Type};
+use crate::utils::{
+    branch_target, called_function_name, filter_builtins_from_arguments, is_panic_result_match,
+    storage_var_name,
+};
+use cairo_lang_sierra::extensions::ConcreteLibfunc;
+use cairo_lang_sierra::program::GenStatement;
+use fxhash::FxHashSet;
+
+/// Return true if the function name looks like an initializer e.g. initialize, initializer, init or init_owner
+pub fn is_initializer(function_name: &str) -> bool {
+    let name = function_name
+        .rsplit_once("::")
+        .map_or(function_name, |(_, name)| name)
+        .to_lowercase();
+    name == "init" || name.starts_with("init_") || name.starts_with("initialize")
+}
+
+/// Return true if every storage write of the function, direct or in the functions it calls,
+/// is done only after asserting a flag the function also writes is not set
+pub fn is_guarded_by_flag(compilation_unit: &CompilationUnit, function: &Function) -> bool {
+    let call_tree: Vec<&Function> = compilation_unit
+        .callgraph()
+        .reachable_from(&function.name())
+        .iter()
+        .filter_map(|name| compilation_unit.function_by_name(name))
+        .collect();
+
+    // A flag set by the function must be checked before to prevent running it again
+    let flags: HashSet<String> = call_tree
+        .iter()
+        .flat_map(|f| f.storage_vars_written())
+        .filter_map(|s| called_function_name(compilation_unit.registry(), s))
+        .map(|name| storage_var_name(&name).to_string())
+        .collect();
+    let writing_functions: HashSet<String> = call_tree
+        .iter()
+        .filter(|f| f.storage_vars_written().next().is_some())
+        .map(|f| f.name())
+        .collect();
+
+    // The storage writes and the calls to functions writing the storage
+    let write_sites: Vec<usize> = function
+        .get_statements()
+        .iter()
+        .enumerate()
+        .filter(|(_, stmt)| {
+            called_function_name(compilation_unit.registry(), stmt).is_some_and(|name| {
+                compilation_unit
+                    .function_by_name(&name)
+                    .is_some_and(|f| *f.ty() == Type::Storage && name.ends_with("write"))
+                    || compilation_unit
+                        .callgraph()
+                        .reachable_from(&name)
+                        .iter()
+                        .any(|reached| writing_functions.contains(reached))
+            })
+        })
+        .map(|(i, _)| function.entry_point() + i)
+        .collect();
+    if write_sites.is_empty() {
+        return false;
+    }
+
+    let guards = flag_guards(compilation_unit, function, &flags, &mut HashSet::new());
+    write_sites.iter().all(|write| {
+        guards
+            .iter()
+            .any(|guard| *guard != *write && function.instruction_dominates(*guard, *write))
+    })
+}
+
+/// Return the pc of the statements executed only when a flag is not set
+/// i.e. the target of the branch continuing when a value read from a flag is checked and the other branch always panics
+/// or the statement following a call to a function returning only after such check
+fn flag_guards(
+    compilation_unit: &CompilationUnit,
+    function: &Function,
+    flags: &HashSet<String>,
+    visiting: &mut HashSet<String>,
+) -> Vec<usize> {
+    let mut guards = Vec::new();
+    let taint = match compilation_unit.get_taint(&function.name()) {
+        Some(taint) => taint,
+        None => return guards,
+    };
+    visiting.insert(function.name());
+
+    // The last value returned is the value read, the others are the builtins
+    let flags_read: FxHashSet<WrapperVariable> = function
+        .storage_vars_read()
+        .filter(|stmt| {
+            called_function_name(compilation_unit.registry(), stmt)
+                .is_some_and(|name| flags.contains(storage_var_name(&name)))
+        })
+        .filter_map(|stmt| match stmt {
+            GenStatement::Invocation(invoc) => invoc.branches[0].results.last(),
+            GenStatement::Return(_) => None,
+        })
+        .map(|var| WrapperVariable::new(function.name(), var.id))
+        .collect();
+
+    for (i, stmt) in function.get_statements().iter().enumerate() {
+        let pc = function.entry_point() + i;
+        let invoc = match stmt {
+            GenStatement::Invocation(invoc) => invoc,
+            GenStatement::Return(_) => continue,
+        };
+        let libfunc = compilation_unit
+            .registry()
+            .get_libfunc(&invoc.libfunc_id)
+            .expect("Library function not found in the registry");
+
+        if invoc.branches.len() == 2 {
+            // Matching a PanicResult only propagates the panic of the function called
+            // The builtins are passed along every call, they don't carry the flag
+            let user_args =
+                filter_builtins_from_arguments(libfunc.param_signatures(), invoc.args.clone());
+            if is_panic_result_match(libfunc)
+                || !user_args.iter().any(|arg| {
+                    taint.taints_any_sources(
+                        &flags_read,
+                        &WrapperVariable::new(function.name(), arg.id),
+                    )
+                })
+            {
+                continue;
+            }
+            let targets: Vec<usize> = invoc
+                .branches
+                .iter()
+                .map(|branch| branch_target(branch, pc))
+                .collect();
+            if always_panics_from(function, compilation_unit.registry(), targets[0]) {
+                guards.push(targets[1]);
+            } else if always_panics_from(function, compilation_unit.registry(), targets[1]) {
+                guards.push(targets[0]);
+            }
+        } else if let Some(called) = called_function_name(compilation_unit.registry(), stmt)
+            .and_then(|name| compilation_unit.function_by_name(&name))
+            .filter(|called| {
+                matches!(called.ty(), Type::Private | Type::Loop)
+                    && !visiting.contains(&called.name())
+            })
+        {
+            let called_guards = flag_guards(compilation_unit, called, flags, visiting);
+            if returns_only_after_guards(compilation_unit, called, &called_guards) {
+                guards.push(pc + 1);
+            }
+        }
+    }
+
+    visiting.remove(&function.name());
+    guards
+}
+
+/// Return true if every return of the function without a panic is done after one of the guards
+fn returns_only_after_guards(
+    compilation_unit: &CompilationUnit,
+    function: &Function,
+    guards: &[usize],
+) -> bool {
+    !guards.is_empty()
+        && function
+            .get_cfg()
+            .get_basic_blocks()
+            .iter()
+            .filter(|bb| {
+                bb.get_outgoing_basic_blocks().is_empty()
+                    && !is_panic_block(bb, compilation_unit.registry())
+            })
+            .filter_map(|bb| bb.last_instruction().map(|i| i.get_pc()))
+            .all(|exit| {
+                guards
+                    .iter()
+                    .any(|guard| function.instruction_dominates(*guard, exit))
+            })
+}
@@ -0,0 +1,651 @@
+use super::dataflow::{Analysis, AnalysisState, Domain, Forward};
+use crate::core::cfg::Cfg;
+use crate::core::function::Function;
+use crate::core::{basic_block::BasicBlock, instruction::Instruction};
+use cairo_felt::Felt252;
+use cairo_lang_sierra::extensions::const_type::ConstConcreteLibfunc;
+use cairo_lang_sierra::extensions::core::{
+    CoreConcreteLibfunc, CoreLibfunc, CoreType, CoreTypeConcrete,
+};
+use cairo_lang_sierra::extensions::felt252::{
+    Felt252BinaryOperationConcrete, Felt252BinaryOperator, Felt252Concrete,
+};
+use cairo_lang_sierra::extensions::int::signed::{SintConcrete, SintTraits};
+use cairo_lang_sierra::extensions::int::signed128::Sint128Concrete;
+use cairo_lang_sierra::extensions::int::unsigned::{UintConcrete, UintTraits};
+use cairo_lang_sierra::extensions::int::unsigned128::Uint128Concrete;
+use cairo_lang_sierra::extensions::int::{IntMulTraits, IntOperator};
+use cairo_lang_sierra::extensions::is_zero::IsZeroTraits;
+use cairo_lang_sierra::extensions::mem::MemConcreteLibfunc;
+use cairo_lang_sierra::extensions::structure::StructConcreteLibfunc;
+use cairo_lang_sierra::ids::{ConcreteTypeId, VarId};
+use cairo_lang_sierra::program::{GenStatement, GenericArg};
+use cairo_lang_sierra::program_registry::ProgramRegistry;
+use num_bigint::BigInt;
+use num_integer::Integer;
+use num_traits::{One, Zero};
+use std::collections::HashMap;
+
+const U256_TYPE: &str = "core::integer::u256";
+
+/// Closed interval of integers
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Interval {
+    pub lo: BigInt,
+    pub hi: BigInt,
+}
+
+impl Interval {
+    pub fn new(lo: BigInt, hi: BigInt) -> Self {
+        Interval { lo, hi }
+    }
+
+    pub fn constant(c: BigInt) -> Self {
+        Interval {
+            lo: c.clone(),
+            hi: c,
+        }
+    }
+
+    /// Range of the values of a felt252
+    pub fn felt252() -> Self {
+        Interval::new(
+            BigInt::zero(),
+            BigInt::from(Felt252::prime()) - BigInt::one(),
+        )
+    }
+
+    /// Range of the values of an unsigned integer of the given bits
+    pub fn unsigned(bits: u32) -> Self {
+        Interval::new(BigInt::zero(), (BigInt::one() << bits) - BigInt::one())
+    }
+
+    /// Range of the values of a signed integer of the given bits
+    pub fn signed(bits: u32) -> Self {
+        Interval::new(
+            -(BigInt::one() << (bits - 1)),
+            (BigInt::one() << (bits - 1)) - BigInt::one(),
+        )
+    }
+
+    /// Return the value if the interval has a single value
+    pub fn as_constant(&self) -> Option<&BigInt> {
+        if self.lo == self.hi {
+            Some(&self.lo)
+        } else {
+            None
+        }
+    }
+
+    pub fn contains(&self, value: &BigInt) -> bool {
+        self.lo <= *value && *value <= self.hi
+    }
+
+    pub fn is_subset_of(&self, other: &Interval) -> bool {
+        other.lo <= self.lo && self.hi <= other.hi
+    }
+
+    /// Smallest interval containing both intervals
+    pub fn hull(&self, other: &Interval) -> Interval {
+        Interval::new(
+            self.lo.clone().min(other.lo.clone()),
+            self.hi.clone().max(other.hi.clone()),
+        )
+    }
+
+    /// Return None if the intervals don't overlap
+    pub fn intersect(&self, other: &Interval) -> Option<Interval> {
+        let lo = self.lo.clone().max(other.lo.clone());
+        let hi = self.hi.clone().min(other.hi.clone());
+        if lo <= hi {
+            Some(Interval::new(lo, hi))
+        } else {
+            None
+        }
+    }
+
+    pub fn add(&self, other: &Interval) -> Interval {
+        Interval::new(&self.lo + &other.lo, &self.hi + &other.hi)
+    }
+
+    pub fn sub(&self, other: &Interval) -> Interval {
+        Interval::new(&self.lo - &other.hi, &self.hi - &other.lo)
+    }
+
+    pub fn mul(&self, other: &Interval) -> Interval {
+        let products = [
+            &self.lo * &other.lo,
+            &self.lo * &other.hi,
+            &self.hi * &other.lo,
+            &self.hi * &other.hi,
+        ];
+        Interval::new(
+            products.iter().min().unwrap().clone(),
+            products.iter().max().unwrap().clone(),
+        )
+    }
+
+    /// Shift the interval by a value
+    fn shift(&self, value: &BigInt) -> Interval {
+        Interval::new(&self.lo + value, &self.hi + value)
+    }
+
+    /// Remove 0 from the interval when it's one of the bounds
+    fn without_zero(&self) -> Option<Interval> {
+        if self.lo.is_zero() && self.hi.is_zero() {
+            None
+        } else if self.lo.is_zero() {
+            Some(Interval::new(BigInt::one(), self.hi.clone()))
+        } else if self.hi.is_zero() {
+            Some(Interval::new(self.lo.clone(), -BigInt::one()))
+        } else {
+            Some(self.clone())
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum IntervalDomain {
+    Bottom,
+    Top,
+    /// Variable -> values it can have
+    /// A variable not present can have any value of its type
+    State(HashMap<VarId, Interval>),
+}
+
+impl IntervalDomain {
+    /// Return the values the variable can have, None if nothing is known
+    pub fn interval(&self, var: &VarId) -> Option<&Interval> {
+        match self {
+            IntervalDomain::State(intervals) => intervals.get(var),
+            _ => None,
+        }
+    }
+
+    /// Return the value of the variable if it's a constant
+    pub fn constant(&self, var: &VarId) -> Option<&BigInt> {
+        self.interval(var).and_then(|i| i.as_constant())
+    }
+}
+
+impl Domain for IntervalDomain {
+    fn bottom() -> Self {
+        Self::Bottom
+    }
+
+    fn top() -> Self {
+        Self::Top
+    }
+
+    fn join(&mut self, other: &Self) -> bool {
+        let res = match (&self, other) {
+            // If self is Top or other is Bottom we don't need to do anything
+            (Self::Top, _) | (_, Self::Bottom) => return false,
+            // Keep only the variables known in both states with the hull of their intervals
+            (Self::State(a), Self::State(b)) => {
+                let new_state: HashMap<VarId, Interval> = a
+                    .iter()
+                    .filter_map(|(var, interval)| {
+                        b.get(var).map(|other| (var.clone(), interval.hull(other)))
+                    })
+                    .collect();
+                if new_state == *a {
+                    return false;
+                }
+                Self::State(new_state)
+            }
+            (Self::Bottom, Self::State(b)) => Self::State(b.clone()),
+            _ => Self::Top,
+        };
+
+        *self = res;
+        true
+    }
+
+    fn widen(&mut self, other: &Self) -> bool {
+        match (&self, other) {
+            // The variables whose values are still growing can have any value of their type
+            (Self::State(a), Self::State(b)) => {
+                let new_state: HashMap<VarId, Interval> = a
+                    .iter()
+                    .filter(|(var, interval)| {
+                        b.get(*var)
+                            .is_some_and(|other| other.is_subset_of(interval))
+                    })
+                    .map(|(var, interval)| (var.clone(), interval.clone()))
+                    .collect();
+                if new_state == *a {
+                    return false;
+                }
+                *self = Self::State(new_state);
+                true
+            }
+            _ => self.join(other),
+        }
+    }
+}
+
+/// Constant propagation and interval analysis of the felt252, integer and u256 variables
+/// Sierra functions don't have loops (they are lowered to recursive functions)
+/// the widening only makes the analysis terminate on a CFG with loops
+#[derive(Clone, Debug)]
+pub struct IntervalAnalysis;
+
+impl Analysis for IntervalAnalysis {
+    type Direction = Forward;
+    type Domain = IntervalDomain;
+
+    fn bottom_value(&self) -> Self::Domain {
+        Self::Domain::Bottom
+    }
+
+    fn transfer_function(
+        &self,
+        _basic_block: &BasicBlock,
+        state: &mut Self::Domain,
+        instruction: &Instruction,
+        _functions: &[Function],
+        registry: &ProgramRegistry<CoreType, CoreLibfunc>,
+    ) {
+        if *state == IntervalDomain::Bottom {
+            *state = IntervalDomain::State(HashMap::new());
+        }
+
+        if let (IntervalDomain::State(intervals), GenStatement::Invocation(invoc)) =
+            (state, instruction.get_statement())
+        {
+            let inputs: HashMap<VarId, Interval> = invoc
+                .args
+                .iter()
+                .filter_map(|arg| intervals.get(arg).map(|i| (arg.clone(), i.clone())))
+                .collect();
+            // Sierra can reuse the same variable id, what we knew about it is not valid anymore
+            for var in invoc.branches.iter().flat_map(|b| b.results.iter()) {
+                intervals.remove(var);
+            }
+
+            let libfunc = registry
+                .get_libfunc(&invoc.libfunc_id)
+                .expect("Library function not found in the registry");
+            let operation = match numeric_operation(libfunc, registry) {
+                Some(operation) => operation,
+                None => return,
+            };
+
+            let get = |var: &VarId, range: &Interval| -> Interval {
+                inputs.get(var).cloned().unwrap_or_else(|| range.clone())
+            };
+            let mut new_intervals: Vec<(VarId, Interval)> = Vec::new();
+            let mut set = |branch: usize, result: usize, interval: Option<Interval>| {
+                if let (Some(var), Some(interval)) = (
+                    invoc
+                        .branches
+                        .get(branch)
+                        .and_then(|b| b.results.get(result)),
+                    interval,
+                ) {
+                    new_intervals.push((var.clone(), interval));
+                }
+            };
+
+            match operation {
+                NumericOperation::Const(c) => set(0, 0, Some(Interval::constant(c))),
+                NumericOperation::Copy => {
+                    if let Some(interval) = invoc.args.last().and_then(|a| inputs.get(a)) {
+                        for i in 0..invoc.branches[0].results.len() {
+                            set(0, i, Some(interval.clone()));
+                        }
+                    }
+                }
+                NumericOperation::Felt252(operator, c) => {
+                    let felt252 = Interval::felt252();
+                    let lhs = inputs.get(&invoc.args[0]);
+                    let rhs = c
+                        .map(Interval::constant)
+                        .or_else(|| invoc.args.get(1).and_then(|a| inputs.get(a)).cloned());
+                    if let (Some(lhs), Some(rhs)) = (lhs, rhs) {
+                        let result = match operator {
+                            Felt252BinaryOperator::Add => Some(lhs.add(&rhs)),
+                            Felt252BinaryOperator::Sub => Some(lhs.sub(&rhs)),
+                            Felt252BinaryOperator::Mul => Some(lhs.mul(&rhs)),
+                            Felt252BinaryOperator::Div => None,
+                        };
+                        // When the result wraps around the prime we don't know its value
+                        set(0, 0, result.filter(|r| r.is_subset_of(&felt252)));
+                    }
+                }
+                NumericOperation::IsZero(range) => {
+                    set(1, 0, get(&invoc.args[0], &range).without_zero());
+                }
+                NumericOperation::Overflowing(operator, range) => {
+                    let lhs = get(&invoc.args[1], &range);
+                    let rhs = get(&invoc.args[2], &range);
+                    let result = match operator {
+                        IntOperator::OverflowingAdd => lhs.add(&rhs),
+                        IntOperator::OverflowingSub => lhs.sub(&rhs),
+                    };
+                    // The first branch is taken when the result is in the range
+                    // the others when it overflows or underflows and the result wraps around
+                    set(0, 1, result.intersect(&range));
+                    let modulus = &range.hi - &range.lo + BigInt::one();
+                    let wrapped = [result.shift(&modulus), result.shift(&-modulus)]
+                        .iter()
+                        .filter_map(|r| r.intersect(&range))
+                        .reduce(|a, b| a.hull(&b));
+                    for branch in 1..invoc.branches.len() {
+                        set(branch, 1, wrapped.clone());
+                    }
+                }
+                NumericOperation::Divmod(range) => {
+                    let lhs = get(&invoc.args[1], &range);
+                    let rhs = get(&invoc.args[2], &range);
+                    // The divisor is a NonZero
+                    let divisor_lo = rhs.lo.clone().max(BigInt::one());
+                    let divisor_hi = rhs.hi.clone().max(BigInt::one());
+                    set(
+                        0,
+                        1,
+                        Some(Interval::new(&lhs.lo / &divisor_hi, &lhs.hi / divisor_lo)),
+                    );
+                    set(
+                        0,
+                        2,
+                        Some(Interval::new(
+                            BigInt::zero(),
+                            (divisor_hi - BigInt::one()).min(lhs.hi.clone()),
+                        )),
+                    );
+                }
+                NumericOperation::WideMul(range) => {
+                    let lhs = get(&invoc.args[0], &range);
+                    let rhs = get(&invoc.args[1], &range);
+                    set(0, 0, Some(lhs.mul(&rhs)));
+                }
+                NumericOperation::ToFelt252(range) => {
+                    // Negative values are represented as prime - value
+                    set(
+                        0,
+                        0,
+                        Some(get(&invoc.args[0], &range))
+                            .filter(|i| i.is_subset_of(&Interval::felt252())),
+                    );
+                }
+                NumericOperation::U256Construct => {
+                    let u128_range = Interval::unsigned(128);
+                    let low = get(&invoc.args[0], &u128_range);
+                    let high = get(&invoc.args[1], &u128_range);
+                    set(
+                        0,
+                        0,
+                        Some(Interval::new(
+                            (high.lo << 128) + low.lo,
+                            (high.hi << 128) + low.hi,
+                        )),
+                    );
+                }
+                NumericOperation::FromFelt252(range) => {
+                    let value = if range.lo.is_zero() {
+                        inputs
+                            .get(&invoc.args[1])
+                            .and_then(|i| i.intersect(&range))
+                            .unwrap_or(range)
+                    } else {
+                        range
+                    };
+                    set(0, 1, Some(value));
+                }
+            }
+
+            intervals.extend(new_intervals);
+        }
+    }
+}
+
+impl IntervalAnalysis {
+    /// Return the state right before the instruction at pc is executed
+    pub fn state_before(
+        function: &Function,
+        pc: usize,
+        registry: &ProgramRegistry<CoreType, CoreLibfunc>,
+    ) -> IntervalDomain {
+        let basic_block = function
+            .basic_block_at(pc)
+            .and_then(|id| function.get_cfg().get_basic_block(id));
+        let state: Option<&AnalysisState<IntervalAnalysis>> =
+            basic_block.and_then(|bb| function.analyses().intervals.get(&bb.get_id()));
+
+        match (basic_block, state) {
+            (Some(basic_block), Some(state)) => {
+                let mut current = state.pre.clone();
+                for instruction in basic_block
+                    .get_instructions()
+                    .iter()
+                    .take_while(|i| i.get_pc() < pc)
+                {
+                    IntervalAnalysis.transfer_function(
+                        basic_block,
+                        &mut current,
+                        instruction,
+                        &[],
+                        registry,
+                    );
+                }
+                current
+            }
+            _ => IntervalDomain::Top,
+        }
+    }
+}
+
+/// Operations on felt252 and integers the analysis understands
+enum NumericOperation {
+    /// A constant value
+    Const(BigInt),
+    /// dup, snapshot_take, store_temp, store_local and rename
+    Copy,
+    /// felt252 binary operation, the constant is the second operand for the with const variant
+    Felt252(Felt252BinaryOperator, Option<BigInt>),
+    /// The non-zero value is returned in the second branch
+    IsZero(Interval),
+    /// Overflowing add or sub of integers of the range
+    Overflowing(IntOperator, Interval),
+    Divmod(Interval),
+    WideMul(Interval),
+    ToFelt252(Interval),
+    FromFelt252(Interval),
+    /// A u256 built from its low and high u128
+    U256Construct,
+}
+
+fn numeric_operation(
+    libfunc: &CoreConcreteLibfunc,
+    registry: &ProgramRegistry<CoreType, CoreLibfunc>,
+) -> Option<NumericOperation> {
+    match libfunc {
+        CoreConcreteLibfunc::Const(ConstConcreteLibfunc::AsImmediate(c)) => {
+            const_value(&c.const_type, registry).map(NumericOperation::Const)
+        }
+        CoreConcreteLibfunc::Struct(StructConcreteLibfunc::Construct(s))
+            if s.signature.branch_signatures[0].vars[0]
+                .ty
+                .debug_name
+                .as_deref()
+                == Some(U256_TYPE) =>
+        {
+            Some(NumericOperation::U256Construct)
+        }
+        CoreConcreteLibfunc::Felt252(felt252) => match felt252 {
+            Felt252Concrete::Const(c) => Some(NumericOperation::Const(
+                c.c.mod_floor(&BigInt::from(Felt252::prime())),
+            )),
+            Felt252Concrete::BinaryOperation(Felt252BinaryOperationConcrete::WithVar(op)) => {
+                Some(NumericOperation::Felt252(op.operator, None))
+            }
+            Felt252Concrete::BinaryOperation(Felt252BinaryOperationConcrete::WithConst(op)) => {
+                Some(NumericOperation::Felt252(op.operator, Some(op.c.clone())))
+            }
+            Felt252Concrete::IsZero(_) => Some(NumericOperation::IsZero(Interval::felt252())),
+        },
+        CoreConcreteLibfunc::Dup(_)
+        | CoreConcreteLibfunc::SnapshotTake(_)
+        | CoreConcreteLibfunc::Mem(
+            MemConcreteLibfunc::StoreTemp(_)
+            | MemConcreteLibfunc::StoreLocal(_)
+            | MemConcreteLibfunc::Rename(_),
+        ) => Some(NumericOperation::Copy),
+        CoreConcreteLibfunc::Uint8(libfunc) => uint_operation(libfunc, 8),
+        CoreConcreteLibfunc::Uint16(libfunc) => uint_operation(libfunc, 16),
+        CoreConcreteLibfunc::Uint32(libfunc) => uint_operation(libfunc, 32),
+        CoreConcreteLibfunc::Uint64(libfunc) => uint_operation(libfunc, 64),
+        CoreConcreteLibfunc::Uint128(libfunc) => {
+            let range = Interval::unsigned(128);
+            match libfunc {
+                Uint128Concrete::Const(c) => Some(NumericOperation::Const(c.c.into())),
+                Uint128Concrete::Operation(op) => {
+                    Some(NumericOperation::Overflowing(op.operator, range))
+                }
+                Uint128Concrete::Divmod(_) => Some(NumericOperation::Divmod(range)),
+                Uint128Concrete::IsZero(_) => Some(NumericOperation::IsZero(range)),
+                Uint128Concrete::ToFelt252(_) => Some(NumericOperation::ToFelt252(range)),
+                Uint128Concrete::FromFelt252(_) => Some(NumericOperation::FromFelt252(range)),
+                _ => None,
+            }
+        }
+        CoreConcreteLibfunc::Sint8(libfunc) => sint_operation(libfunc, 8),
+        CoreConcreteLibfunc::Sint16(libfunc) => sint_operation(libfunc, 16),
+        CoreConcreteLibfunc::Sint32(libfunc) => sint_operation(libfunc, 32),
+        CoreConcreteLibfunc::Sint64(libfunc) => sint_operation(libfunc, 64),
+        CoreConcreteLibfunc::Sint128(libfunc) => {
+            let range = Interval::signed(128);
+            match libfunc {
+                Sint128Concrete::Const(c) => Some(NumericOperation::Const(c.c.into())),
+                Sint128Concrete::Operation(op) => {
+                    Some(NumericOperation::Overflowing(op.operator, range))
+                }
+                Sint128Concrete::IsZero(_) => Some(NumericOperation::IsZero(range)),
+                Sint128Concrete::ToFelt252(_) => Some(NumericOperation::ToFelt252(range)),
+                Sint128Concrete::FromFelt252(_) => Some(NumericOperation::FromFelt252(range)),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+/// Return the value of a const type of a felt252, an integer or a u256
+/// e.g. Const<u128, 5> or Const<core::integer::u256, Const<u128, low>, Const<u128, high>>
+fn const_value(
+    const_type: &ConcreteTypeId,
+    registry: &ProgramRegistry<CoreType, CoreLibfunc>,
+) -> Option<BigInt> {
+    let const_type = match registry.get_type(const_type).ok()? {
+        CoreTypeConcrete::Const(const_type) => const_type,
+        _ => return None,
+    };
+    match const_type.inner_data.as_slice() {
+        [GenericArg::Value(value)] => match registry.get_type(&const_type.inner_ty).ok()? {
+            CoreTypeConcrete::Felt252(_) => Some(value.mod_floor(&BigInt::from(Felt252::prime()))),
+            _ => Some(value.clone()),
+        },
+        [GenericArg::Type(low), GenericArg::Type(high)]
+            if const_type.inner_ty.debug_name.as_deref() == Some(U256_TYPE) =>
+        {
+            Some((const_value(high, registry)? << 128) + const_value(low, registry)?)
+        }
+        _ => None,
+    }
+}
+
+fn uint_operation<T: UintTraits + IntMulTraits + IsZeroTraits>(
+    libfunc: &UintConcrete<T>,
+    bits: u32,
+) -> Option<NumericOperation> {
+    let range = Interval::unsigned(bits);
+    match libfunc {
+        UintConcrete::Const(c) => Some(NumericOperation::Const(c.c.into())),
+        UintConcrete::Operation(op) => Some(NumericOperation::Overflowing(op.operator, range)),
+        UintConcrete::Divmod(_) => Some(NumericOperation::Divmod(range)),
+        UintConcrete::WideMul(_) => Some(NumericOperation::WideMul(range)),
+        UintConcrete::IsZero(_) => Some(NumericOperation::IsZero(range)),
+        UintConcrete::ToFelt252(_) => Some(NumericOperation::ToFelt252(range)),
+        UintConcrete::FromFelt252(_) => Some(NumericOperation::FromFelt252(range)),
+        _ => None,
+    }
+}
+
+fn sint_operation<T: SintTraits + IntMulTraits + IsZeroTraits>(
+    libfunc: &SintConcrete<T>,
+    bits: u32,
+) -> Option<NumericOperation> {
+    let range = Interval::signed(bits);
+    match libfunc {
+        SintConcrete::Const(c) => Some(NumericOperation::Const(c.c.into())),
+        SintConcrete::Operation(op) => Some(NumericOperation::Overflowing(op.operator, range)),
+        SintConcrete::WideMul(_) => Some(NumericOperation::WideMul(range)),
+        SintConcrete::IsZero(_) => Some(NumericOperation::IsZero(range)),
+        SintConcrete::ToFelt252(_) => Some(NumericOperation::ToFelt252(range)),
+        SintConcrete::FromFelt252(_) => Some(NumericOperation::FromFelt252(range)),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analysis::dataflow::Engine;
+    use crate::core::cfg::CfgRegular;
+    use cairo_lang_sierra::ProgramParser;
+
+    #[test]
+    fn widening_of_loop() {
+        // [1] is a constant, [2] is incremented at each iteration of the loop
+        let program = ProgramParser::new()
+            .parse(
+                "
+                type felt252 = felt252;
+                type NonZero<felt252> = NonZero<felt252>;
+
+                libfunc felt252_const<5> = felt252_const<5>;
+                libfunc felt252_const<0> = felt252_const<0>;
+                libfunc felt252_add_const<1> = felt252_add_const<1>;
+                libfunc felt252_is_zero = felt252_is_zero;
+                libfunc branch_align = branch_align;
+
+                felt252_const<5>() -> ([1]);
+                felt252_const<0>() -> ([2]);
+                felt252_add_const<1>([2]) -> ([2]);
+                felt252_is_zero([0]) { fallthrough() 5([3]) };
+                branch_align() -> ();
+                branch_align() -> ();
+                return([2]);
+
+                test::f@0([0]: felt252) -> (felt252);
+                ",
+            )
+            .unwrap();
+        let registry = ProgramRegistry::<CoreType, CoreLibfunc>::new(&program).unwrap();
+        // Sierra doesn't allow to jump backwards, the loop is added to the CFG
+        // 0 -> 1 -> 2 -> 1
+        // 1 -> 3
+        let statements = program.statements;
+        let cfg = CfgRegular::from_basic_blocks(
+            vec![
+                statements[0..2].to_vec(),
+                statements[2..4].to_vec(),
+                statements[4..5].to_vec(),
+                statements[5..7].to_vec(),
+            ],
+            &[(0, 1), (1, 2), (1, 3), (2, 1)],
+            &registry,
+        );
+
+        let mut engine = Engine::new(&cfg, IntervalAnalysis);
+        engine.run_analysis(&[], &registry);
+
+        for basic_block in [1, 3] {
+            let state = &engine.result()[&basic_block].pre;
+            assert_eq!(state.constant(&VarId::new(1)), Some(&BigInt::from(5)));
+            assert_eq!(state.interval(&VarId::new(2)), None);
+        }
+    }
+}
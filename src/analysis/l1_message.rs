@@ -0,0 +1,249 @@
+use super::def_use::Definition;
+use super::interval::{Interval, IntervalAnalysis};
+use super::taint::WrapperVariable;
+use crate::core::compilation_unit::CompilationUnit;
+use crate::core::function::Function;
+use crate::utils::{
+    branch_target, called_function_name, filter_builtins_from_arguments, is_panic_result_match,
+};
+use cairo_lang_sierra::extensions::core::CoreConcreteLibfunc;
+use cairo_lang_sierra::extensions::enm::EnumConcreteLibfunc;
+use cairo_lang_sierra::extensions::starknet::StarkNetConcreteLibfunc;
+use cairo_lang_sierra::extensions::ConcreteLibfunc;
+use cairo_lang_sierra::ids::VarId;
+use cairo_lang_sierra::program::GenStatement;
+use fxhash::FxHashSet;
+use num_bigint::BigInt;
+use num_traits::{One, Zero};
+
+/// A message sent to L1 with send_message_to_l1_syscall
+pub struct MessageToL1 {
+    /// Pc of the syscall
+    pub pc: usize,
+    /// L1 address receiving the message
+    pub to_address: VarId,
+    /// Span of felt252 sent
+    pub payload: VarId,
+}
+
+/// Return the messages sent to L1 in the function
+pub fn messages_to_l1(compilation_unit: &CompilationUnit, function: &Function) -> Vec<MessageToL1> {
+    let mut messages = Vec::new();
+
+    for (i, stmt) in function.get_statements().iter().enumerate() {
+        if let GenStatement::Invocation(invoc) = stmt {
+            let libfunc = compilation_unit
+                .registry()
+                .get_libfunc(&invoc.libfunc_id)
+                .expect("Library function not found in the registry");
+
+            if let CoreConcreteLibfunc::StarkNet(StarkNetConcreteLibfunc::SendMessageToL1(_)) =
+                libfunc
+            {
+                let user_args =
+                    filter_builtins_from_arguments(libfunc.param_signatures(), invoc.args.clone());
+                if let [to_address, payload] = user_args.as_slice() {
+                    messages.push(MessageToL1 {
+                        pc: function.entry_point() + i,
+                        to_address: to_address.clone(),
+                        payload: payload.clone(),
+                    });
+                }
+            }
+        }
+    }
+
+    messages
+}
+
+/// Return true if the instruction at pc is only reached through one branch of a check on a value
+/// derived from the same parameters as the variable e.g. after an assert on the value
+pub fn is_validated_before(
+    compilation_unit: &CompilationUnit,
+    function: &Function,
+    var: &VarId,
+    pc: usize,
+) -> bool {
+    let taint = match compilation_unit.get_taint(&function.name()) {
+        Some(taint) => taint,
+        None => return false,
+    };
+    let sources = sources(compilation_unit, function, var);
+
+    function
+        .get_statements()
+        .iter()
+        .enumerate()
+        .any(|(i, stmt)| match stmt {
+            GenStatement::Invocation(invoc) if invoc.branches.len() > 1 => {
+                let branch_pc = function.entry_point() + i;
+                // Matching a PanicResult only checks if a function called panicked
+                if is_panic_result_match(
+                    compilation_unit
+                        .registry()
+                        .get_libfunc(&invoc.libfunc_id)
+                        .expect("Library function not found in the registry"),
+                ) {
+                    return false;
+                }
+                // When the branches join again before pc the check doesn't prevent reaching it
+                invoc.branches.iter().any(|branch| {
+                    function.instruction_dominates(branch_target(branch, branch_pc), pc)
+                }) && invoc.args.iter().any(|arg| {
+                    taint.taints_any_sources(
+                        &sources,
+                        &WrapperVariable::new(function.name(), arg.id),
+                    )
+                })
+            }
+            _ => false,
+        })
+}
+
+/// Return true if the value of the variable is known to fit in an L1 address (< 2^160) at pc
+/// i.e. pc is only reached after a successful conversion to EthAddress
+/// or a comparison with a bound not bigger than 2^160 of a value derived from the same parameters
+pub fn is_bounded_as_l1_address_before(
+    compilation_unit: &CompilationUnit,
+    function: &Function,
+    var: &VarId,
+    pc: usize,
+) -> bool {
+    let registry = compilation_unit.registry();
+    let l1_address_bound: BigInt = BigInt::one() << 160;
+
+    if IntervalAnalysis::state_before(function, pc, registry)
+        .interval(var)
+        .is_some_and(|interval| {
+            interval.is_subset_of(&Interval::new(BigInt::zero(), l1_address_bound.clone() - 1))
+        })
+    {
+        return true;
+    }
+
+    let taint = match compilation_unit.get_taint(&function.name()) {
+        Some(taint) => taint,
+        None => return false,
+    };
+    let sources = sources(compilation_unit, function, var);
+
+    function
+        .get_statements()
+        .iter()
+        .enumerate()
+        .any(|(i, stmt)| {
+            let check_pc = function.entry_point() + i;
+            let invoc = match stmt {
+                GenStatement::Invocation(invoc) => invoc,
+                GenStatement::Return(_) => return false,
+            };
+            match l1_address_check(compilation_unit, function, check_pc, &l1_address_bound) {
+                Some((checked, bounded_branch)) => {
+                    function.instruction_dominates(
+                        branch_target(&invoc.branches[bounded_branch], check_pc),
+                        pc,
+                    ) && taint.taints_any_sources(
+                        &sources,
+                        &WrapperVariable::new(function.name(), checked.id),
+                    )
+                }
+                None => false,
+            }
+        })
+}
+
+/// Return the value checked by the statement at pc and the index of the branch taken when it's smaller than the bound
+/// if the statement matches the result of Felt252TryIntoEthAddress::try_into
+/// or the result of a u256 comparison with a constant not bigger than the bound
+fn l1_address_check(
+    compilation_unit: &CompilationUnit,
+    function: &Function,
+    pc: usize,
+    bound: &BigInt,
+) -> Option<(VarId, usize)> {
+    let registry = compilation_unit.registry();
+    let invoc = match &function.get_statements()[pc - function.entry_point()] {
+        GenStatement::Invocation(invoc) => invoc,
+        GenStatement::Return(_) => return None,
+    };
+    let matched_ty = match registry
+        .get_libfunc(&invoc.libfunc_id)
+        .expect("Library function not found in the registry")
+    {
+        CoreConcreteLibfunc::Enum(EnumConcreteLibfunc::Match(enum_match)) => {
+            enum_match.signature.param_signatures[0]
+                .ty
+                .debug_name
+                .clone()?
+        }
+        _ => return None,
+    };
+
+    let def_pc = match function
+        .def_use(registry)
+        .value_definitions(&invoc.args[0], pc)
+        .as_slice()
+    {
+        [value] => match value.definition {
+            Definition::Statement(def_pc) => def_pc,
+            Definition::Parameter => return None,
+        },
+        _ => return None,
+    };
+    let call = &function.get_statements()[def_pc - function.entry_point()];
+    let name = called_function_name(registry, call)?;
+    let args = match call {
+        GenStatement::Invocation(call) => &call.args,
+        GenStatement::Return(_) => return None,
+    };
+
+    if matched_ty.contains("Option") && name.ends_with("Felt252TryIntoEthAddress::try_into") {
+        // The first branch is Some
+        return args.last().map(|checked| (checked.clone(), 0));
+    }
+    if matched_ty != "core::bool" || !name.starts_with("core::integer::U256PartialOrd::") {
+        return None;
+    }
+    // The builtins are the first arguments, the second branch is true
+    let (lhs, rhs) = match args.as_slice() {
+        [.., lhs, rhs] => (lhs, rhs),
+        _ => return None,
+    };
+    let state = IntervalAnalysis::state_before(function, def_pc, registry);
+    let constant = |var: &VarId| state.constant(var).cloned();
+    match name.rsplit("::").next() {
+        Some("lt") => constant(rhs)
+            .filter(|c| c <= bound)
+            .map(|_| (lhs.clone(), 1)),
+        Some("le") => constant(rhs)
+            .filter(|c| c < bound)
+            .map(|_| (lhs.clone(), 1)),
+        Some("gt") => constant(lhs)
+            .filter(|c| c <= bound)
+            .map(|_| (rhs.clone(), 1)),
+        Some("ge") => constant(lhs)
+            .filter(|c| c < bound)
+            .map(|_| (rhs.clone(), 1)),
+        _ => None,
+    }
+}
+
+/// Return the parameters the variable is derived from and the variable itself
+fn sources(
+    compilation_unit: &CompilationUnit,
+    function: &Function,
+    var: &VarId,
+) -> FxHashSet<WrapperVariable> {
+    let var = WrapperVariable::new(function.name(), var.id);
+    let mut sources: FxHashSet<WrapperVariable> = match compilation_unit.get_taint(&function.name())
+    {
+        Some(taint) => function
+            .params()
+            .map(|param| WrapperVariable::new(function.name(), param.id.id))
+            .filter(|param| taint.taints_any_sinks(param, &FxHashSet::from_iter([var.clone()])))
+            .collect(),
+        None => FxHashSet::default(),
+    };
+    sources.insert(var);
+    sources
+}
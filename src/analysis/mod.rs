@@ -1,5 +1,17 @@
+pub mod account;
+pub mod block_info;
+pub mod caller_check;
+pub mod callgraph;
 pub mod dataflow;
+pub mod def_use;
+pub mod dominators;
+pub mod initializer;
 pub mod instructions;
+pub mod interval;
+pub mod l1_message;
+pub mod panics;
+pub mod randomness;
 pub mod reentrancy;
+pub mod signature;
 pub mod taint;
 pub mod traversal;
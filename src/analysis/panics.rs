@@ -0,0 +1,118 @@
+use std::collections::HashSet;
+
+use crate::analysis::def_use::{Definition, Value};
+use crate::core::basic_block::BasicBlock;
+use crate::core::cfg::Cfg;
+use crate::core::compilation_unit::CompilationUnit;
+use crate::core::function::Function;
+use crate::utils::branch_target;
+use cairo_lang_sierra::extensions::core::{CoreConcreteLibfunc, CoreLibfunc, CoreType};
+use cairo_lang_sierra::extensions::enm::EnumConcreteLibfunc;
+use cairo_lang_sierra::ids::VarId;
+use cairo_lang_sierra::program::GenStatement;
+use cairo_lang_sierra::program_registry::ProgramRegistry;
+
+/// Return true if the basic block returns a panic
+/// i.e. it ends with a return of the Err variant of a PanicResult initialized in the block
+pub fn is_panic_block(
+    basic_block: &BasicBlock,
+    registry: &ProgramRegistry<CoreType, CoreLibfunc>,
+) -> bool {
+    let returned = match basic_block.last_instruction().map(|i| i.get_statement()) {
+        Some(GenStatement::Return(vars)) => match vars.last() {
+            Some(var) => var,
+            None => return false,
+        },
+        _ => return false,
+    };
+
+    basic_block.get_instructions().iter().any(|instruction| {
+        if let GenStatement::Invocation(invoc) = instruction.get_statement() {
+            if invoc.branches[0].results.first() != Some(returned) {
+                return false;
+            }
+            if let CoreConcreteLibfunc::Enum(EnumConcreteLibfunc::Init(enum_init)) = registry
+                .get_libfunc(&invoc.libfunc_id)
+                .expect("Library function not found in the registry")
+            {
+                return enum_init.index == 1
+                    && enum_init.signature.branch_signatures[0].vars[0]
+                        .ty
+                        .debug_name
+                        .as_ref()
+                        .is_some_and(|name| name.starts_with("core::panics::PanicResult"));
+            }
+        }
+        false
+    })
+}
+
+/// Return true if every path of the function starting at the instruction at pc ends with a panic
+pub fn always_panics_from(
+    function: &Function,
+    registry: &ProgramRegistry<CoreType, CoreLibfunc>,
+    pc: usize,
+) -> bool {
+    let cfg = function.get_cfg();
+    let start = match function.basic_block_at(pc) {
+        Some(start) => start,
+        None => return false,
+    };
+
+    let mut visited = HashSet::new();
+    let mut worklist = vec![start];
+    while let Some(bb_id) = worklist.pop() {
+        if !visited.insert(bb_id) {
+            continue;
+        }
+        let bb = cfg
+            .get_basic_block(bb_id)
+            .expect("Basic block not found in the CFG");
+        if bb.get_outgoing_basic_blocks().is_empty() {
+            if !is_panic_block(bb, registry) {
+                return false;
+            }
+        } else {
+            worklist.extend(bb.get_outgoing_basic_blocks().iter().copied());
+        }
+    }
+
+    true
+}
+
+/// Return true if the program panics when the Option held by the variable defined at pc is None
+pub fn is_unwrapped(
+    compilation_unit: &CompilationUnit,
+    function: &Function,
+    option: &VarId,
+    pc: usize,
+) -> bool {
+    let registry = compilation_unit.registry();
+    function
+        .def_use(registry)
+        .value_uses(&Value::new(option.clone(), Definition::Statement(pc)))
+        .into_iter()
+        .any(|pc| {
+            let invoc = match &function.get_statements()[pc - function.entry_point()] {
+                GenStatement::Invocation(invoc) => invoc,
+                GenStatement::Return(_) => return false,
+            };
+            match registry
+                .get_libfunc(&invoc.libfunc_id)
+                .expect("Library function not found in the registry")
+            {
+                // The None variant is the second one
+                CoreConcreteLibfunc::Enum(EnumConcreteLibfunc::Match(_)) => {
+                    invoc.branches.get(1).is_some_and(|none| {
+                        always_panics_from(function, registry, branch_target(none, pc))
+                    })
+                }
+                CoreConcreteLibfunc::FunctionCall(f_called) => {
+                    let name = f_called.function.id.debug_name.as_ref().unwrap();
+                    name.starts_with("core::option::")
+                        && (name.ends_with("::unwrap") || name.ends_with("::expect"))
+                }
+                _ => false,
+            }
+        })
+}
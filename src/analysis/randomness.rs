@@ -0,0 +1,88 @@
+use super::taint::WrapperVariable;
+use crate::core::compilation_unit::CompilationUnit;
+use crate::core::function::Function;
+use crate::utils::{is_narrowing_libfunc, is_panic_result_match};
+use cairo_lang_sierra::extensions::core::CoreConcreteLibfunc;
+use cairo_lang_sierra::extensions::int::unsigned::UintConcrete;
+use cairo_lang_sierra::extensions::int::unsigned128::Uint128Concrete;
+use cairo_lang_sierra::extensions::int::unsigned256::Uint256Concrete;
+use cairo_lang_sierra::extensions::starknet::StarkNetConcreteLibfunc;
+use cairo_lang_sierra::extensions::ConcreteLibfunc;
+use cairo_lang_sierra::program::GenStatement;
+use fxhash::FxHashSet;
+
+/// Return the values not known before the transaction is executed
+/// i.e. the parameters, the values returned by the user defined functions (e.g. a secret read from the storage)
+/// and the values read from the storage or returned by other contracts
+pub fn unpredictable_values(
+    compilation_unit: &CompilationUnit,
+    function: &Function,
+) -> FxHashSet<WrapperVariable> {
+    let mut values: FxHashSet<WrapperVariable> = function
+        .params()
+        .map(|param| WrapperVariable::new(function.name(), param.id.id))
+        .collect();
+
+    for stmt in function.get_statements() {
+        if let GenStatement::Invocation(invoc) = stmt {
+            let libfunc = compilation_unit
+                .registry()
+                .get_libfunc(&invoc.libfunc_id)
+                .expect("Library function not found in the registry");
+
+            let is_unpredictable = match libfunc {
+                CoreConcreteLibfunc::StarkNet(
+                    StarkNetConcreteLibfunc::StorageRead(_)
+                    | StarkNetConcreteLibfunc::CallContract(_)
+                    | StarkNetConcreteLibfunc::LibraryCall(_),
+                ) => true,
+                CoreConcreteLibfunc::FunctionCall(f_called) => f_called
+                    .function
+                    .id
+                    .debug_name
+                    .as_ref()
+                    .is_some_and(|name| !name.starts_with("core::")),
+                _ => false,
+            };
+            if is_unpredictable {
+                values.extend(
+                    invoc.branches[0]
+                        .results
+                        .iter()
+                        .map(|var| WrapperVariable::new(function.name(), var.id)),
+                );
+            }
+        }
+    }
+
+    values
+}
+
+/// Return true if the libfunc reduces its input with a modulo or decides a branch
+pub fn is_randomness_sink(libfunc: &CoreConcreteLibfunc) -> bool {
+    match libfunc {
+        CoreConcreteLibfunc::Uint8(UintConcrete::Divmod(_))
+        | CoreConcreteLibfunc::Uint16(UintConcrete::Divmod(_))
+        | CoreConcreteLibfunc::Uint32(UintConcrete::Divmod(_))
+        | CoreConcreteLibfunc::Uint64(UintConcrete::Divmod(_))
+        | CoreConcreteLibfunc::Uint128(Uint128Concrete::Divmod(_))
+        | CoreConcreteLibfunc::Uint256(Uint256Concrete::Divmod(_)) => true,
+        // Done in a core function
+        CoreConcreteLibfunc::FunctionCall(f_called) => f_called
+            .function
+            .id
+            .debug_name
+            .as_ref()
+            .is_some_and(|name| {
+                name.starts_with("core::integer::")
+                    && (name.ends_with("Rem::rem")
+                        || name.ends_with("DivRem::div_rem")
+                        || name.ends_with("RemEq::rem_eq"))
+            }),
+        // Matching a PanicResult only checks if a function called panicked
+        _ if is_panic_result_match(libfunc) => false,
+        // A conversion branches only on the size of the value
+        _ if is_narrowing_libfunc(libfunc) => false,
+        _ => libfunc.branch_signatures().len() > 1,
+    }
+}
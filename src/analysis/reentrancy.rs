@@ -1,15 +1,17 @@
-use super::dataflow::{Analysis, Domain, Forward};
+use super::dataflow::{Analysis, AnalysisState, Domain, Forward, InterproceduralAnalysis};
 use crate::core::cfg::Cfg;
 use crate::core::function::Function;
 use crate::core::{basic_block::BasicBlock, function::Type, instruction::Instruction};
 use cairo_lang_sierra::extensions::core::CoreConcreteLibfunc;
 use cairo_lang_sierra::extensions::core::{CoreLibfunc, CoreType};
+use cairo_lang_sierra::extensions::starknet::StarkNetConcreteLibfunc;
 use cairo_lang_sierra::program::GenStatement;
 use cairo_lang_sierra::program_registry::ProgramRegistry;
 use std::collections::{HashMap, HashSet};
 
 #[derive(Clone, Debug, PartialEq, Eq, Default)]
 pub struct ReentrancyInfo {
+    /// Calls to other contracts, including library calls
     pub external_calls: HashSet<BasicBlock>,
     pub storage_variables_read: HashSet<BasicBlock>,
     pub storage_variables_written: HashSet<BasicBlock>,
@@ -18,6 +20,33 @@ pub struct ReentrancyInfo {
     pub events: HashSet<BasicBlock>,
 }
 
+impl ReentrancyInfo {
+    /// Add everything done by a function called
+    fn add_summary(&mut self, summary: &ReentrancyInfo) {
+        self.external_calls.extend(summary.external_calls.clone());
+        self.storage_variables_read
+            .extend(summary.storage_variables_read.clone());
+        self.storage_variables_written
+            .extend(summary.storage_variables_written.clone());
+        for (call, variables_read) in summary.variables_read_before_calls.iter() {
+            self.variables_read_before_calls
+                .entry(call.clone())
+                .or_default()
+                .extend(variables_read.clone());
+        }
+        self.events.extend(summary.events.clone());
+    }
+
+    /// Add a call to another contract, through an ABI dispatcher or a syscall, done in the basic block
+    fn add_external_call(&mut self, basic_block: &BasicBlock) {
+        self.external_calls.insert(basic_block.clone());
+        self.variables_read_before_calls.insert(
+            basic_block.clone(),
+            HashSet::from_iter(self.storage_variables_read.clone()),
+        );
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum ReentrancyDomain {
     Bottom,
@@ -90,26 +119,6 @@ impl Analysis for ReentrancyAnalysis {
         instruction: &Instruction,
         functions: &[Function],
         registry: &ProgramRegistry<CoreType, CoreLibfunc>,
-    ) {
-        ReentrancyAnalysis::transfer_function_helper(
-            basic_block,
-            state,
-            instruction,
-            functions,
-            registry,
-            &mut HashSet::new(),
-        );
-    }
-}
-
-impl ReentrancyAnalysis {
-    fn transfer_function_helper(
-        basic_block: &BasicBlock,
-        state: &mut <ReentrancyAnalysis as Analysis>::Domain,
-        instruction: &Instruction,
-        functions: &[Function],
-        registry: &ProgramRegistry<CoreType, CoreLibfunc>,
-        private_functions_seen: &mut HashSet<String>,
     ) {
         match state {
             ReentrancyDomain::Bottom => {
@@ -123,93 +132,39 @@ impl ReentrancyAnalysis {
                         .expect("Library function not found in the registry");
                     if let CoreConcreteLibfunc::FunctionCall(f_called) = lib_func {
                         // We search for the function called in our list of functions to know its type
-                        for function in functions {
-                            let function_name = function.name();
-                            if function_name.as_str()
+                        // the user defined functions called are handled with their summary
+                        if let Some(function) = functions.iter().find(|function| {
+                            function.name().as_str()
                                 == f_called.function.id.debug_name.as_ref().unwrap()
-                            {
-                                match function.ty() {
-                                    Type::Storage => {
-                                        if function_name.ends_with("::read") {
-                                            inner_state
-                                                .storage_variables_read
-                                                .insert(basic_block.clone());
-                                        } else if function_name.ends_with("::write") {
-                                            inner_state
-                                                .storage_variables_written
-                                                .insert(basic_block.clone());
-                                        }
+                        }) {
+                            match function.ty() {
+                                Type::Storage => {
+                                    if function.name().ends_with("::read") {
+                                        inner_state
+                                            .storage_variables_read
+                                            .insert(basic_block.clone());
+                                    } else if function.name().ends_with("::write") {
+                                        inner_state
+                                            .storage_variables_written
+                                            .insert(basic_block.clone());
                                     }
-                                    Type::Event => {
-                                        inner_state.events.insert(basic_block.clone());
-                                    }
-                                    // External and View are needed because it's possible to call self declared external functions within a private function
-                                    Type::Private | Type::Loop | Type::External | Type::View => {
-                                        if let GenStatement::Invocation(invoc) =
-                                            instruction.get_statement()
-                                        {
-                                            let lib_func =
-                                                registry.get_libfunc(&invoc.libfunc_id).expect(
-                                                    "Library function not found in the registry",
-                                                );
-                                            if let CoreConcreteLibfunc::FunctionCall(f_called) =
-                                                lib_func
-                                            {
-                                                for function in functions {
-                                                    let function_name = function.name();
-                                                    if function_name.as_str()
-                                                        == f_called
-                                                            .function
-                                                            .id
-                                                            .debug_name
-                                                            .as_ref()
-                                                            .unwrap()
-                                                    {
-                                                        if private_functions_seen
-                                                            .contains(&function_name)
-                                                        {
-                                                            break;
-                                                        }
-                                                        private_functions_seen
-                                                            .insert(function_name);
-
-                                                        for bb in
-                                                            function.get_cfg().get_basic_blocks()
-                                                        {
-                                                            if let Some(instruction) =
-                                                                bb.get_function_call()
-                                                            {
-                                                                ReentrancyAnalysis::transfer_function_helper(
-                                                                    bb,
-                                                                    state,
-                                                                    instruction,
-                                                                    functions,
-                                                                    registry,
-                                                                    private_functions_seen,
-                                                                );
-                                                            }
-                                                        }
-                                                        break;
-                                                    }
-                                                }
-                                            }
-                                        }
-                                    }
-                                    Type::AbiCallContract => {
-                                        inner_state.external_calls.insert(basic_block.clone());
-                                        inner_state.variables_read_before_calls.insert(
-                                            basic_block.clone(),
-                                            HashSet::from_iter(
-                                                inner_state.storage_variables_read.clone(),
-                                            ),
-                                        );
-                                    }
-
-                                    _ => (),
                                 }
-                                break;
+                                Type::Event => {
+                                    inner_state.events.insert(basic_block.clone());
+                                }
+                                Type::AbiCallContract | Type::AbiLibraryCall => {
+                                    inner_state.add_external_call(basic_block);
+                                }
+                                _ => (),
                             }
                         }
+                    } else if let CoreConcreteLibfunc::StarkNet(
+                        StarkNetConcreteLibfunc::CallContract(_)
+                        | StarkNetConcreteLibfunc::LibraryCall(_),
+                    ) = lib_func
+                    {
+                        // The syscalls are called directly without an ABI dispatcher
+                        inner_state.add_external_call(basic_block);
                     }
                 }
             }
@@ -218,3 +173,59 @@ impl ReentrancyAnalysis {
         };
     }
 }
+
+impl InterproceduralAnalysis for ReentrancyAnalysis {
+    /// The summary is the union of the states of every basic block
+    /// because the storage variables written and the events are not propagated
+    fn summarize(
+        &self,
+        cfg: &dyn Cfg,
+        state: &HashMap<usize, AnalysisState<Self>>,
+    ) -> Self::Domain {
+        let mut summary = self.bottom_value();
+        for bb in cfg.get_basic_blocks() {
+            self.join_summary(&mut summary, &state[&bb.get_id()].post);
+        }
+        summary
+    }
+
+    fn join_summary(&self, summary: &mut Self::Domain, other: &Self::Domain) -> bool {
+        match (&mut *summary, other) {
+            (ReentrancyDomain::Top, _) | (_, ReentrancyDomain::Bottom) => false,
+            (ReentrancyDomain::Bottom, _) => {
+                *summary = other.clone();
+                true
+            }
+            (_, ReentrancyDomain::Top) => {
+                *summary = ReentrancyDomain::Top;
+                true
+            }
+            (ReentrancyDomain::State(a), ReentrancyDomain::State(b)) => {
+                let before = a.clone();
+                a.add_summary(b);
+                **a != *before
+            }
+        }
+    }
+
+    fn apply_summary(
+        &self,
+        _basic_block: &BasicBlock,
+        state: &mut Self::Domain,
+        _instruction: &Instruction,
+        summary: &Self::Domain,
+    ) {
+        match (state, summary) {
+            (ReentrancyDomain::State(inner_state), ReentrancyDomain::State(summary)) => {
+                // The storage variables read by the caller are read before the calls done in the function called
+                let mut called = summary.clone();
+                for variables_read in called.variables_read_before_calls.values_mut() {
+                    variables_read.extend(inner_state.storage_variables_read.clone());
+                }
+                inner_state.add_summary(&called);
+            }
+            (state, ReentrancyDomain::Top) => *state = ReentrancyDomain::Top,
+            _ => (),
+        }
+    }
+}
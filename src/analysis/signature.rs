@@ -0,0 +1,296 @@
+use super::def_use::{results_at, Definition, Value};
+use super::taint::WrapperVariable;
+use crate::core::compilation_unit::CompilationUnit;
+use crate::core::function::{Function, Type};
+use crate::utils::{
+    filter_builtins_from_arguments, filter_builtins_from_returns, is_panic_result_match,
+    storage_var_name,
+};
+use cairo_lang_sierra::extensions::core::CoreConcreteLibfunc;
+use cairo_lang_sierra::extensions::ec::EcConcreteLibfunc;
+use cairo_lang_sierra::extensions::enm::EnumConcreteLibfunc;
+use cairo_lang_sierra::extensions::starknet::secp256::{
+    Secp256ConcreteLibfunc, Secp256OpConcreteLibfunc,
+};
+use cairo_lang_sierra::extensions::starknet::StarkNetConcreteLibfunc;
+use cairo_lang_sierra::extensions::structure::StructConcreteLibfunc;
+use cairo_lang_sierra::ids::VarId;
+use cairo_lang_sierra::program::GenStatement;
+use fxhash::FxHashSet;
+
+/// Core functions verifying a signature, the first argument is the message hash
+/// the last value returned is the result of the verification
+const SIGNATURE_FUNCTIONS: [&str; 6] = [
+    "core::ecdsa::check_ecdsa_signature",
+    "core::ecdsa::recover_public_key",
+    "core::starknet::secp256_trait::is_valid_signature",
+    "core::starknet::secp256_trait::recover_public_key",
+    "core::starknet::eth_signature::is_eth_signature_valid",
+    "core::starknet::eth_signature::verify_eth_signature",
+];
+
+/// Signature functions panicking when the signature is invalid, they don't return a result to check
+const PANICKING_SIGNATURE_FUNCTIONS: [&str; 1] =
+    ["core::starknet::eth_signature::verify_eth_signature"];
+
+const TX_INFO_TYPES: [&str; 2] = [
+    "core::starknet::info::TxInfo",
+    "core::starknet::info::v2::TxInfo",
+];
+
+/// Index of the transaction_hash, chain_id and nonce members of TxInfo
+const TX_INFO_REPLAY_PROTECTION_MEMBERS: [usize; 3] = [4, 5, 6];
+
+/// A call to a function verifying a signature
+pub struct SignatureVerification {
+    /// Pc of the call
+    pub pc: usize,
+    /// Name of the function called without generic arguments
+    pub name: String,
+    /// Message hash signed, None when a point of the curve is recovered directly with a libfunc
+    pub message_hash: Option<VarId>,
+    /// Result of the verification, None when the function panics on an invalid signature
+    pub result: Option<VarId>,
+}
+
+/// Return the signature verifications done in the function
+pub fn signature_verifications(
+    compilation_unit: &CompilationUnit,
+    function: &Function,
+) -> Vec<SignatureVerification> {
+    let mut verifications = Vec::new();
+
+    for (i, stmt) in function.get_statements().iter().enumerate() {
+        if let GenStatement::Invocation(invoc) = stmt {
+            let libfunc = compilation_unit
+                .registry()
+                .get_libfunc(&invoc.libfunc_id)
+                .expect("Library function not found in the registry");
+
+            match libfunc {
+                CoreConcreteLibfunc::FunctionCall(f_called) => {
+                    let name = f_called.function.id.debug_name.as_ref().unwrap();
+                    // Remove the generic arguments of the secp256 functions
+                    let name = name.split("::<").next().unwrap();
+                    if !SIGNATURE_FUNCTIONS.contains(&name) {
+                        continue;
+                    }
+
+                    let user_args = filter_builtins_from_arguments(
+                        &f_called.signature.param_signatures,
+                        invoc.args.clone(),
+                    );
+                    if let Some(message_hash) = user_args.first() {
+                        verifications.push(SignatureVerification {
+                            pc: function.entry_point() + i,
+                            name: name.to_string(),
+                            message_hash: Some(message_hash.clone()),
+                            result: if PANICKING_SIGNATURE_FUNCTIONS.contains(&name) {
+                                None
+                            } else {
+                                invoc.branches[0].results.last().cloned()
+                            },
+                        });
+                    }
+                }
+                // The point recovered from the r value of the signature, the functions above
+                // are inlined to these libfuncs when the signature is verified by hand
+                CoreConcreteLibfunc::Ec(EcConcreteLibfunc::PointFromX(_))
+                | CoreConcreteLibfunc::StarkNet(StarkNetConcreteLibfunc::Secp256(
+                    Secp256ConcreteLibfunc::K1(Secp256OpConcreteLibfunc::GetPointFromX(_))
+                    | Secp256ConcreteLibfunc::R1(Secp256OpConcreteLibfunc::GetPointFromX(_)),
+                )) => {
+                    verifications.push(SignatureVerification {
+                        pc: function.entry_point() + i,
+                        name: invoc.libfunc_id.to_string(),
+                        message_hash: None,
+                        result: invoc.branches[0].results.last().cloned(),
+                    });
+                }
+                _ => (),
+            }
+        }
+    }
+
+    verifications
+}
+
+/// Return true if the result of the verification is used
+/// When the function can panic the result is wrapped in a PanicResult and a tuple
+pub fn is_result_used(
+    compilation_unit: &CompilationUnit,
+    function: &Function,
+    verification: &SignatureVerification,
+) -> bool {
+    let registry = compilation_unit.registry();
+    let def_use = function.def_use(registry);
+
+    let mut worklist = match &verification.result {
+        Some(result) => vec![Value::new(
+            result.clone(),
+            Definition::Statement(verification.pc),
+        )],
+        None => return true,
+    };
+    let mut visited = FxHashSet::default();
+    while let Some(value) = worklist.pop() {
+        if !visited.insert(value.clone()) {
+            continue;
+        }
+        for pc in def_use.value_uses(&value) {
+            let invoc = match &function.get_statements()[pc - function.entry_point()] {
+                GenStatement::Invocation(invoc) => invoc,
+                GenStatement::Return(_) => return true,
+            };
+            match registry
+                .get_libfunc(&invoc.libfunc_id)
+                .expect("Library function not found in the registry")
+            {
+                CoreConcreteLibfunc::Drop(_) => (),
+                // Unwrap the Ok variant of the PanicResult
+                libfunc if is_panic_result_match(libfunc) => {
+                    worklist.extend(results_at(&invoc.branches[0].results, pc));
+                }
+                CoreConcreteLibfunc::Struct(StructConcreteLibfunc::Deconstruct(_))
+                | CoreConcreteLibfunc::Enum(EnumConcreteLibfunc::Init(_)) => {
+                    worklist.extend(results_at(&invoc.branches[0].results, pc));
+                }
+                // The value returned by unwrap_syscall is the Ok variant of the Result
+                CoreConcreteLibfunc::FunctionCall(f_called)
+                    if f_called
+                        .function
+                        .id
+                        .debug_name
+                        .as_ref()
+                        .is_some_and(|name| name.ends_with("::unwrap_syscall")) =>
+                {
+                    let returned = filter_builtins_from_returns(
+                        &f_called.signature.branch_signatures[0].vars,
+                        invoc.branches[0].results.clone(),
+                    );
+                    worklist.extend(results_at(&returned, pc));
+                }
+                // Unwrap the Ok variant of the Result returned by the syscalls
+                CoreConcreteLibfunc::Enum(EnumConcreteLibfunc::Match(enum_type))
+                    if enum_type.signature.param_signatures[0]
+                        .ty
+                        .debug_name
+                        .as_ref()
+                        .is_some_and(|ty| ty.starts_with("core::result::Result")) =>
+                {
+                    worklist.extend(results_at(&invoc.branches[0].results, pc));
+                }
+                _ => return true,
+            }
+        }
+    }
+
+    false
+}
+
+/// Return true if the message hash is derived from the transaction hash, the chain id or the nonce of the transaction
+/// or from a storage variable written after the verification, like a nonce incremented
+pub fn is_replay_protected(
+    compilation_unit: &CompilationUnit,
+    function: &Function,
+    verification: &SignatureVerification,
+) -> bool {
+    let taint = match compilation_unit.get_taint(&function.name()) {
+        Some(taint) => taint,
+        None => return false,
+    };
+    // The message hash is combined with the recovered point later on, it can't be told apart
+    let message_hash = match &verification.message_hash {
+        Some(message_hash) => message_hash,
+        None => return true,
+    };
+
+    let mut sources = FxHashSet::default();
+    for stmt in function.get_statements().iter() {
+        if let GenStatement::Invocation(invoc) = stmt {
+            let libfunc = compilation_unit
+                .registry()
+                .get_libfunc(&invoc.libfunc_id)
+                .expect("Library function not found in the registry");
+
+            match libfunc {
+                CoreConcreteLibfunc::Struct(StructConcreteLibfunc::Deconstruct(struct_type))
+                    if struct_type
+                        .signature
+                        .param_signatures
+                        .first()
+                        .is_some_and(|p| TX_INFO_TYPES.contains(&p.ty.to_string().as_str())) =>
+                {
+                    sources.extend(
+                        TX_INFO_REPLAY_PROTECTION_MEMBERS
+                            .iter()
+                            .filter_map(|member| invoc.branches[0].results.get(*member))
+                            .map(|var| WrapperVariable::new(function.name(), var.id)),
+                    );
+                }
+                CoreConcreteLibfunc::FunctionCall(f_called) => {
+                    let name = f_called.function.id.debug_name.as_ref().unwrap();
+                    let is_storage_read = compilation_unit
+                        .function_by_name(name)
+                        .is_some_and(|f| *f.ty() == Type::Storage)
+                        && name.ends_with("read");
+                    if is_storage_read
+                        && is_storage_var_written_after(
+                            compilation_unit,
+                            function,
+                            storage_var_name(name),
+                            verification.pc,
+                        )
+                    {
+                        sources.extend(
+                            invoc.branches[0]
+                                .results
+                                .last()
+                                .map(|var| WrapperVariable::new(function.name(), var.id)),
+                        );
+                    }
+                }
+                _ => (),
+            }
+        }
+    }
+
+    taint.taints_any_sources(
+        &sources,
+        &WrapperVariable::new(function.name(), message_hash.id),
+    )
+}
+
+/// Return true if the storage variable is written after the instruction at pc
+fn is_storage_var_written_after(
+    compilation_unit: &CompilationUnit,
+    function: &Function,
+    storage_var: &str,
+    pc: usize,
+) -> bool {
+    function
+        .get_statements()
+        .iter()
+        .enumerate()
+        .any(|(i, stmt)| match stmt {
+            GenStatement::Invocation(invoc) => {
+                match compilation_unit
+                    .registry()
+                    .get_libfunc(&invoc.libfunc_id)
+                    .expect("Library function not found in the registry")
+                {
+                    CoreConcreteLibfunc::FunctionCall(f_called) => {
+                        let name = f_called.function.id.debug_name.as_ref().unwrap();
+                        compilation_unit
+                            .function_by_name(name)
+                            .is_some_and(|f| *f.ty() == Type::Storage)
+                            && name.ends_with("write")
+                            && storage_var_name(name) == storage_var
+                            && function.instruction_dominates(pc, function.entry_point() + i)
+                    }
+                    _ => false,
+                }
+            }
+            GenStatement::Return(_) => false,
+        })
+}
@@ -26,6 +26,12 @@ pub struct DetectArgs {
     #[arg(long, num_args(0..))]
     safe_external_calls: Option<Vec<String>>,
 
+    /// Operations that require a caller check when reached from an external function
+    /// (storage-write, authorization-write, replace-class, external-call or part of a function name)
+    /// default to storage-write and external-call
+    #[arg(long, num_args(0..))]
+    privileged_sinks: Option<Vec<String>>,
+
     /// Detectors to run
     #[arg(long, num_args(0..), conflicts_with_all(["exclude", "exclude_informational", "exclude_low", "exclude_medium", "exclude_high"]))]
     detect: Option<Vec<String>>,
@@ -58,6 +64,7 @@ impl From<&DetectArgs> for CoreOpts {
             corelib: args.corelib.clone(),
             contract_path: args.contract_path.clone(),
             safe_external_calls: args.safe_external_calls.clone(),
+            privileged_sinks: args.privileged_sinks.clone(),
         }
     }
 }
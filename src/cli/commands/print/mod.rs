@@ -38,6 +38,7 @@ impl From<&PrintArgs> for CoreOpts {
             corelib: args.corelib.clone(),
             contract_path: args.contract_path.clone(),
             safe_external_calls: args.safe_external_calls.clone(),
+            privileged_sinks: None,
         }
     }
 }
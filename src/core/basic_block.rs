@@ -3,6 +3,7 @@ use crate::core::function::{Function, Type};
 use std::hash::{Hash, Hasher};
 
 use cairo_lang_sierra::extensions::core::{CoreConcreteLibfunc, CoreLibfunc, CoreType};
+use cairo_lang_sierra::extensions::starknet::StarkNetConcreteLibfunc;
 use cairo_lang_sierra::program::GenStatement;
 use cairo_lang_sierra::program_registry::ProgramRegistry;
 
@@ -157,6 +158,11 @@ impl BasicBlock {
         &self.library_call
     }
 
+    /// Return the call to another contract in this basic block, either an external call or a library call
+    pub fn get_contract_call(&self) -> Option<&Instruction> {
+        self.external_call.as_ref().or(self.library_call.as_ref())
+    }
+
     pub fn get_storage_variable_read(&self) -> &Option<Instruction> {
         &self.storage_variable_read
     }
@@ -209,6 +215,17 @@ impl BasicBlock {
                         }
                     }
                 }
+
+                // The syscalls called directly without an ABI dispatcher
+                match lib_func {
+                    CoreConcreteLibfunc::StarkNet(StarkNetConcreteLibfunc::CallContract(_)) => {
+                        self.external_call = Some(instruction.clone())
+                    }
+                    CoreConcreteLibfunc::StarkNet(StarkNetConcreteLibfunc::LibraryCall(_)) => {
+                        self.library_call = Some(instruction.clone())
+                    }
+                    _ => (),
+                }
             }
         }
     }
@@ -253,3 +253,38 @@ impl Cfg for CfgRegular {
         self.basic_blocks.get(id)
     }
 }
+
+#[cfg(test)]
+impl CfgRegular {
+    /// Create a CFG from the statements of each basic block and the edges between the basic blocks
+    /// Unlike the CFG of a Sierra function it can have loops
+    pub fn from_basic_blocks(
+        basic_blocks: Vec<Vec<SierraStatement>>,
+        edges: &[(usize, usize)],
+        registry: &ProgramRegistry<CoreType, CoreLibfunc>,
+    ) -> Self {
+        let mut pc = 0;
+        let mut cfg = CfgRegular::new();
+        for (id, statements) in basic_blocks.into_iter().enumerate() {
+            let instructions = statements
+                .into_iter()
+                .map(|statement| {
+                    pc += 1;
+                    Instruction::new(pc - 1, statement)
+                })
+                .collect();
+            cfg.basic_blocks.push(BasicBlock::new(
+                "test".to_string(),
+                id,
+                instructions,
+                &[],
+                registry,
+            ));
+        }
+        for (source, destination) in edges {
+            cfg.basic_blocks[*source].add_outgoing_bb(*destination);
+            cfg.basic_blocks[*destination].add_incoming_bb(*source);
+        }
+        cfg
+    }
+}
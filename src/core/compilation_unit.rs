@@ -1,4 +1,7 @@
 use super::function::{Function, Type};
+use crate::analysis::callgraph::CallGraph;
+use crate::analysis::dataflow::InterproceduralEngine;
+use crate::analysis::reentrancy::ReentrancyAnalysis;
 use crate::analysis::taint::Taint;
 use crate::analysis::taint::WrapperVariable;
 use cairo_lang_sierra::extensions::core::{CoreConcreteLibfunc, CoreLibfunc, CoreType};
@@ -25,6 +28,8 @@ pub struct CompilationUnit {
     registry: ProgramRegistry<CoreType, CoreLibfunc>,
     /// Function name to taints
     taint: HashMap<String, Taint>,
+    /// Call graph of the program
+    callgraph: CallGraph,
 }
 
 impl CompilationUnit {
@@ -39,6 +44,7 @@ impl CompilationUnit {
             abi,
             registry,
             taint: HashMap::new(),
+            callgraph: CallGraph::default(),
         }
     }
 
@@ -79,10 +85,18 @@ impl CompilationUnit {
             .map(|event| event.name().rsplit_once("IsEvent::").unwrap().0.to_owned())
     }
 
+    pub fn abi(&self) -> &Contract {
+        &self.abi
+    }
+
     pub fn registry(&self) -> &ProgramRegistry<CoreType, CoreLibfunc> {
         &self.registry
     }
 
+    pub fn callgraph(&self) -> &CallGraph {
+        &self.callgraph
+    }
+
     /// Return true if the variable is tainted i.e. user inputs can control it in some way
     pub fn is_tainted(&self, function_name: String, variable: VarId) -> bool {
         let wrapped_variable = WrapperVariable::new(function_name, variable.id);
@@ -129,7 +143,17 @@ impl CompilationUnit {
             let full_name = f.id.to_string();
             if full_name.contains("::__wrapper_") {
                 // This case happens for cairo >= 2.2.0
-                let function_name = full_name.replace("__wrapper__", "").replace("__", "::");
+                // The wrapped name is the function name or the impl name and the function name joined by __
+                // the function name can start with __ e.g. __execute__ of account contracts
+                let (module, wrapped) = full_name
+                    .split_once("__wrapper__")
+                    .unwrap_or((&full_name, ""));
+                let function_name = match wrapped.split_once("__") {
+                    Some((impl_name, name)) if !impl_name.is_empty() => {
+                        format!("{}{}::{}", module, impl_name, name)
+                    }
+                    _ => format!("{}{}", module, wrapped),
+                };
                 if function_name.ends_with("::constructor") {
                     constructors.insert(function_name);
                 } else {
@@ -314,6 +338,21 @@ impl CompilationUnit {
             .iter_mut()
             .for_each(|f| f.run_analyses(&functions, &self.registry));
 
+        // The reentrancy info of the external functions includes what is done in the functions they call
+        let mut reentrancy = InterproceduralEngine::new(&functions, ReentrancyAnalysis);
+        reentrancy.run_analysis(&self.registry);
+        self.functions
+            .iter_mut()
+            .filter(|f| *f.ty() == Type::External)
+            .for_each(|f| {
+                if let Some(result) = reentrancy.result(&f.name()) {
+                    f.set_reentrancy(result.clone());
+                }
+            });
+
+        // Compute the call graph
+        self.callgraph = CallGraph::new(&self.functions, &self.registry);
+
         // Compute taints
         self.functions.iter().for_each(|f| {
             self.taint
@@ -463,3 +502,18 @@ impl CompilationUnit {
         }
     }
 }
+
+#[cfg(test)]
+impl CompilationUnit {
+    /// Create and analyze a compilation unit from a Sierra program in its textual form
+    pub fn from_sierra(sierra: &str) -> Self {
+        let program = cairo_lang_sierra::ProgramParser::new()
+            .parse(sierra)
+            .expect("Invalid Sierra program");
+        let registry = ProgramRegistry::<CoreType, CoreLibfunc>::new(&program)
+            .expect("Invalid Sierra program");
+        let mut compilation_unit = CompilationUnit::new(program, Contract::default(), registry);
+        compilation_unit.analyze();
+        compilation_unit
+    }
+}
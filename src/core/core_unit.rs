@@ -11,16 +11,19 @@ pub struct CoreOpts {
     pub corelib: Option<PathBuf>,
     pub contract_path: Option<Vec<String>>,
     pub safe_external_calls: Option<Vec<String>>,
+    pub privileged_sinks: Option<Vec<String>>,
 }
 
 pub struct CoreUnit {
     compilation_units: Vec<CompilationUnit>,
     safe_external_calls: Option<Vec<String>>,
+    privileged_sinks: Option<Vec<String>>,
 }
 
 impl CoreUnit {
     pub fn new(opts: CoreOpts) -> Result<Self> {
         let safe_external_calls = opts.safe_external_calls.clone();
+        let privileged_sinks = opts.privileged_sinks.clone();
         let program_compiled = compile(opts)?;
         let compilation_units = program_compiled
             .par_iter()
@@ -37,6 +40,7 @@ impl CoreUnit {
         Ok(CoreUnit {
             compilation_units,
             safe_external_calls,
+            privileged_sinks,
         })
     }
 
@@ -47,4 +51,8 @@ impl CoreUnit {
     pub fn get_safe_external_calls(&self) -> &Option<Vec<String>> {
         &self.safe_external_calls
     }
+
+    pub fn get_privileged_sinks(&self) -> &Option<Vec<String>> {
+        &self.privileged_sinks
+    }
 }
@@ -4,6 +4,9 @@ use std::io::Write;
 use super::cfg::{Cfg, CfgRegular};
 use crate::analysis::dataflow::AnalysisState;
 use crate::analysis::dataflow::Engine;
+use crate::analysis::def_use::DefUse;
+use crate::analysis::dominators::Dominators;
+use crate::analysis::interval::IntervalAnalysis;
 use crate::analysis::reentrancy::ReentrancyAnalysis;
 use crate::utils::BUILTINS;
 use cairo_lang_sierra::extensions::core::{CoreConcreteLibfunc, CoreLibfunc, CoreType};
@@ -15,11 +18,14 @@ use cairo_lang_sierra::program_registry::ProgramRegistry;
 use graphviz_rust::dot_generator::*;
 use graphviz_rust::dot_structures::*;
 use graphviz_rust::printer::{DotPrinter, PrinterContext};
+use once_cell::sync::OnceCell;
 
 #[derive(Clone, Default)]
 pub struct Analyses {
     /// Reentrancy info result
     pub reentrancy: HashMap<usize, AnalysisState<ReentrancyAnalysis>>,
+    /// Constants and intervals of the felt252 and integer variables
+    pub intervals: HashMap<usize, AnalysisState<IntervalAnalysis>>,
 }
 
 #[derive(Clone, Copy, PartialEq, Eq, Debug)]
@@ -86,6 +92,12 @@ pub struct Function {
     loop_functions_calls: Vec<SierraStatement>,
     /// Analyses results
     analyses: Analyses,
+    /// Dominator tree of the regular CFG, computed when first requested
+    dominators: OnceCell<Dominators>,
+    /// Post-dominator tree of the regular CFG, computed when first requested
+    post_dominators: OnceCell<Dominators>,
+    /// Def-use chains and alias classes of the variables, computed when first requested
+    def_use: OnceCell<DefUse>,
 }
 
 impl Function {
@@ -104,6 +116,9 @@ impl Function {
             library_functions_calls: Vec::new(),
             loop_functions_calls: Vec::new(),
             analyses: Analyses::default(),
+            dominators: OnceCell::new(),
+            post_dominators: OnceCell::new(),
+            def_use: OnceCell::new(),
         }
     }
 
@@ -191,6 +206,47 @@ impl Function {
         &self.cfg_regular
     }
 
+    /// Return the dominator tree of the regular CFG
+    pub fn dominators(&self) -> &Dominators {
+        self.dominators
+            .get_or_init(|| Dominators::new(&self.cfg_regular))
+    }
+
+    /// Return the post-dominator tree of the regular CFG
+    pub fn post_dominators(&self) -> &Dominators {
+        self.post_dominators
+            .get_or_init(|| Dominators::new_post_dominators(&self.cfg_regular))
+    }
+
+    /// Return the def-use chains and alias classes of the variables
+    pub fn def_use(&self, registry: &ProgramRegistry<CoreType, CoreLibfunc>) -> &DefUse {
+        self.def_use.get_or_init(|| DefUse::new(self, registry))
+    }
+
+    /// Return the id of the basic block containing the instruction at pc
+    pub fn basic_block_at(&self, pc: usize) -> Option<usize> {
+        self.cfg_regular
+            .get_basic_blocks()
+            .iter()
+            .find(|bb| bb.get_instructions().iter().any(|i| i.get_pc() == pc))
+            .map(|bb| bb.get_id())
+    }
+
+    /// Return true if the instruction at pc a is executed in every path
+    /// from the function's entry to the instruction at pc b
+    pub fn instruction_dominates(&self, a: usize, b: usize) -> bool {
+        match (self.basic_block_at(a), self.basic_block_at(b)) {
+            (Some(bb_a), Some(bb_b)) if bb_a == bb_b => a <= b,
+            (Some(bb_a), Some(bb_b)) => self.dominators().dominates(bb_a, bb_b),
+            _ => false,
+        }
+    }
+
+    /// Return the pc of the first statement of the function
+    pub fn entry_point(&self) -> usize {
+        self.data.entry_point.0
+    }
+
     pub fn analyze(
         &mut self,
         functions: &[Function],
@@ -260,13 +316,29 @@ impl Function {
         functions: &[Function],
         registry: &ProgramRegistry<CoreType, CoreLibfunc>,
     ) {
-        if self.ty.unwrap() == Type::External {
-            let mut reentrancy = Engine::new(&self.cfg_regular, ReentrancyAnalysis);
-            reentrancy.run_analysis(functions, registry);
-            self.analyses.reentrancy = reentrancy.result().clone();
+        if matches!(
+            self.ty.unwrap(),
+            Type::Constructor
+                | Type::External
+                | Type::View
+                | Type::Private
+                | Type::L1Handler
+                | Type::Loop
+        ) {
+            let mut intervals = Engine::new(&self.cfg_regular, IntervalAnalysis);
+            intervals.run_analysis(functions, registry);
+            self.analyses.intervals = intervals.result().clone();
         }
     }
 
+    /// Set the reentrancy info, it's computed for all the functions at once with the summaries of the functions called
+    pub(super) fn set_reentrancy(
+        &mut self,
+        reentrancy: HashMap<usize, AnalysisState<ReentrancyAnalysis>>,
+    ) {
+        self.analyses.reentrancy = reentrancy;
+    }
+
     pub(super) fn set_ty(&mut self, ty: Type) {
         self.ty = Some(ty);
     }
@@ -0,0 +1,57 @@
+use std::collections::HashSet;
+
+use super::detector::{Confidence, Detector, Impact, Result};
+use crate::analysis::account::is_execute_entry_point;
+use crate::analysis::caller_check::is_only_callable_by_protocol;
+use crate::core::core_unit::CoreUnit;
+
+#[derive(Default)]
+pub struct AccountExecute {}
+
+impl Detector for AccountExecute {
+    fn name(&self) -> &str {
+        "account-execute"
+    }
+
+    fn description(&self) -> &str {
+        "Detect account execution entry points not checking the caller is the protocol"
+    }
+
+    fn confidence(&self) -> Confidence {
+        Confidence::Medium
+    }
+
+    fn impact(&self) -> Impact {
+        Impact::High
+    }
+
+    fn run(&self, core: &CoreUnit) -> HashSet<Result> {
+        let mut results: HashSet<Result> = HashSet::new();
+        let compilation_units = core.get_compilation_units();
+
+        for compilation_unit in compilation_units {
+            for f in compilation_unit
+                .functions_user_defined()
+                .filter(|f| is_execute_entry_point(f))
+            {
+                // Only the protocol, with the zero address, must be able to execute the calls
+                if is_only_callable_by_protocol(compilation_unit, f) {
+                    continue;
+                }
+
+                let message = format!(
+                    "The account execution function {} doesn't check the caller is the protocol (zero address)",
+                    f.name()
+                );
+                results.insert(Result {
+                    name: self.name().to_string(),
+                    impact: self.impact(),
+                    confidence: self.confidence(),
+                    message,
+                });
+            }
+        }
+
+        results
+    }
+}
@@ -0,0 +1,166 @@
+use std::collections::{BTreeSet, HashSet};
+
+use super::detector::{Confidence, Detector, Impact, Result};
+use crate::analysis::account::is_validate_entry_point;
+use crate::analysis::block_info::block_info_fields;
+use crate::analysis::caller_check::caller_address_values;
+use crate::analysis::def_use::is_dropped;
+use crate::core::compilation_unit::CompilationUnit;
+use crate::core::core_unit::CoreUnit;
+use crate::core::function::{Function, Type};
+use crate::utils::called_function_name;
+use cairo_lang_sierra::extensions::core::CoreConcreteLibfunc;
+use cairo_lang_sierra::extensions::starknet::StarkNetConcreteLibfunc;
+use cairo_lang_sierra::program::GenStatement;
+
+#[derive(Default)]
+pub struct AccountValidate {}
+
+impl Detector for AccountValidate {
+    fn name(&self) -> &str {
+        "account-validate"
+    }
+
+    fn description(&self) -> &str {
+        "Detect account validation entry points calling other contracts or reading the block info or the caller address"
+    }
+
+    fn confidence(&self) -> Confidence {
+        Confidence::Medium
+    }
+
+    fn impact(&self) -> Impact {
+        Impact::Medium
+    }
+
+    fn run(&self, core: &CoreUnit) -> HashSet<Result> {
+        let mut results: HashSet<Result> = HashSet::new();
+        let compilation_units = core.get_compilation_units();
+
+        for compilation_unit in compilation_units {
+            for f in compilation_unit
+                .functions_user_defined()
+                .filter(|f| is_validate_entry_point(f))
+            {
+                let call_tree: Vec<&Function> = compilation_unit
+                    .callgraph()
+                    .reachable_from(&f.name())
+                    .iter()
+                    .filter_map(|name| compilation_unit.function_by_name(name))
+                    .collect();
+
+                let external_calls: BTreeSet<String> = call_tree
+                    .iter()
+                    .flat_map(|function| self.external_calls(compilation_unit, function))
+                    .collect();
+                if !external_calls.is_empty() {
+                    self.add_result(
+                        &mut results,
+                        format!(
+                            "The account validation function {} calls other contracts: {}",
+                            f.name(),
+                            external_calls
+                                .into_iter()
+                                .collect::<Vec<String>>()
+                                .join(", ")
+                        ),
+                    );
+                }
+
+                // The user defined functions of the call tree, the core functions returning the values are called from them
+                let info_used: BTreeSet<String> = call_tree
+                    .iter()
+                    .filter(|function| !matches!(function.ty(), Type::Core))
+                    .flat_map(|function| self.restricted_values_used(compilation_unit, function))
+                    .collect();
+                if !info_used.is_empty() {
+                    self.add_result(
+                        &mut results,
+                        format!(
+                            "The account validation function {} uses values restricted during the validation: {}",
+                            f.name(),
+                            info_used.into_iter().collect::<Vec<String>>().join(", ")
+                        ),
+                    );
+                }
+            }
+        }
+
+        results
+    }
+}
+
+impl AccountValidate {
+    /// Return the calls to other contracts done directly in the function
+    fn external_calls(
+        &self,
+        compilation_unit: &CompilationUnit,
+        function: &Function,
+    ) -> Vec<String> {
+        let mut calls: Vec<String> = function
+            .external_functions_calls()
+            .chain(function.library_functions_calls())
+            .filter_map(|s| called_function_name(compilation_unit.registry(), s))
+            .collect();
+
+        // The syscalls done by the dispatchers generated by the compiler are already reported with their name
+        if matches!(
+            function.ty(),
+            Type::Core | Type::AbiCallContract | Type::AbiLibraryCall
+        ) {
+            return calls;
+        }
+
+        for stmt in function.get_statements() {
+            if let GenStatement::Invocation(invoc) = stmt {
+                if let CoreConcreteLibfunc::StarkNet(
+                    StarkNetConcreteLibfunc::CallContract(_)
+                    | StarkNetConcreteLibfunc::LibraryCall(_),
+                ) = compilation_unit
+                    .registry()
+                    .get_libfunc(&invoc.libfunc_id)
+                    .expect("Library function not found in the registry")
+                {
+                    calls.push(invoc.libfunc_id.debug_name.as_ref().unwrap().to_string());
+                }
+            }
+        }
+
+        calls
+    }
+
+    /// Return the names of the restricted values used in the function
+    /// the block number and timestamp, read from any BlockInfo, and the caller address
+    fn restricted_values_used(
+        &self,
+        compilation_unit: &CompilationUnit,
+        function: &Function,
+    ) -> Vec<String> {
+        let block_info = block_info_fields(compilation_unit, function)
+            .into_iter()
+            .map(|(field, values)| (format!("block {}", field), values));
+        let caller = (
+            "caller address".to_string(),
+            caller_address_values(compilation_unit, function),
+        );
+
+        block_info
+            .chain(std::iter::once(caller))
+            .filter(|(_, values)| {
+                values
+                    .iter()
+                    .any(|value| !is_dropped(function, compilation_unit.registry(), value))
+            })
+            .map(|(name, _)| name)
+            .collect()
+    }
+
+    fn add_result(&self, results: &mut HashSet<Result>, message: String) {
+        results.insert(Result {
+            name: self.name().to_string(),
+            impact: self.impact(),
+            confidence: self.confidence(),
+            message,
+        });
+    }
+}
@@ -0,0 +1,385 @@
+use super::detector::{Confidence, Detector, Impact, Result};
+use crate::analysis::block_info::block_info_fields;
+use crate::analysis::def_use::{is_dropped, Definition, Value};
+use crate::analysis::randomness::{is_randomness_sink, unpredictable_values};
+use crate::analysis::taint::WrapperVariable;
+use crate::core::compilation_unit::CompilationUnit;
+use crate::core::core_unit::CoreUnit;
+use crate::core::function::{Function, Type};
+use crate::utils::{filter_builtins_from_arguments, filter_builtins_from_returns};
+use cairo_lang_sierra::extensions::core::CoreConcreteLibfunc;
+use cairo_lang_sierra::extensions::felt252::Felt252Concrete;
+use cairo_lang_sierra::extensions::int::signed::SintConcrete;
+use cairo_lang_sierra::extensions::int::signed128::Sint128Concrete;
+use cairo_lang_sierra::extensions::int::unsigned::UintConcrete;
+use cairo_lang_sierra::extensions::int::unsigned128::Uint128Concrete;
+use cairo_lang_sierra::extensions::int::unsigned256::Uint256Concrete;
+use cairo_lang_sierra::extensions::starknet::StarkNetConcreteLibfunc;
+use cairo_lang_sierra::extensions::structure::StructConcreteLibfunc;
+use cairo_lang_sierra::extensions::ConcreteLibfunc;
+use cairo_lang_sierra::ids::VarId;
+use cairo_lang_sierra::program::Statement as SierraStatement;
+use fxhash::FxHashSet;
+use std::collections::{BTreeSet, HashSet};
+
+/// Index of the remainder in the results of the divmod libfuncs, after the range check and the quotient
+const REMAINDER_RESULT: usize = 2;
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum BlockInfoUse {
+    Randomness,
+    StrictEquality,
+}
+
+#[derive(Default)]
+pub struct BlockInfoDependence {}
+
+impl Detector for BlockInfoDependence {
+    fn name(&self) -> &str {
+        "block-info-dependence"
+    }
+
+    fn description(&self) -> &str {
+        "Detect usage of the block timestamp or number as a source of randomness or in a strict equality"
+    }
+
+    fn confidence(&self) -> Confidence {
+        Confidence::Medium
+    }
+
+    fn impact(&self) -> Impact {
+        Impact::Low
+    }
+
+    fn run(&self, core: &CoreUnit) -> HashSet<Result> {
+        let mut results: HashSet<Result> = HashSet::new();
+        let compilation_units = core.get_compilation_units();
+
+        for compilation_unit in compilation_units.iter() {
+            for function in compilation_unit.functions_user_defined() {
+                for (field, block_info) in block_info_fields(compilation_unit, function) {
+                    let block_info: FxHashSet<WrapperVariable> = block_info
+                        .iter()
+                        .map(|value| WrapperVariable::new(function.name(), value.var.id))
+                        .collect();
+                    let mut uses = BTreeSet::new();
+                    self.find_uses(
+                        compilation_unit,
+                        function,
+                        &block_info,
+                        &mut HashSet::new(),
+                        &mut uses,
+                    );
+
+                    for block_info_use in uses {
+                        let message = match block_info_use {
+                            BlockInfoUse::Randomness => format!(
+                                "The block {} is used as a source of randomness in the function {}",
+                                field,
+                                function.name()
+                            ),
+                            BlockInfoUse::StrictEquality => format!(
+                                "The block {} is used in a strict equality in the function {}",
+                                field,
+                                function.name()
+                            ),
+                        };
+                        results.insert(Result {
+                            name: self.name().to_string(),
+                            impact: self.impact(),
+                            confidence: self.confidence(),
+                            message,
+                        });
+                    }
+                }
+            }
+        }
+
+        results
+    }
+}
+
+impl BlockInfoDependence {
+    /// Find how the values derived from the sources are used in the function and in the private functions it calls
+    fn find_uses(
+        &self,
+        compilation_unit: &CompilationUnit,
+        function: &Function,
+        sources: &FxHashSet<WrapperVariable>,
+        checked_private_functions: &mut HashSet<String>,
+        uses: &mut BTreeSet<BlockInfoUse>,
+    ) {
+        let taint = match compilation_unit.get_taint(&function.name()) {
+            Some(taint) => taint,
+            None => return,
+        };
+        let is_tainted = |var: &VarId| {
+            taint.taints_any_sources(sources, &WrapperVariable::new(function.name(), var.id))
+        };
+        // A hash mixing the sources with a value not known before the transaction is not predictable
+        let unpredictable: FxHashSet<WrapperVariable> =
+            unpredictable_values(compilation_unit, function)
+                .difference(sources)
+                .cloned()
+                .collect();
+        let is_predictable = |inputs: &[VarId]| {
+            !inputs.iter().any(|input| {
+                let input = WrapperVariable::new(function.name(), input.id);
+                unpredictable.contains(&input) || taint.taints_any_sources(&unpredictable, &input)
+            })
+        };
+
+        // The hashes of the sources, a modulo of a hash is reported only if the hash is predictable
+        let mut hashes: FxHashSet<WrapperVariable> = FxHashSet::default();
+        let mut predictable_hashes: FxHashSet<WrapperVariable> = FxHashSet::default();
+        for (inputs, outputs) in hashes_computed(compilation_unit, function) {
+            if !inputs.iter().any(is_tainted) {
+                continue;
+            }
+            let outputs = outputs
+                .into_iter()
+                .map(|var| WrapperVariable::new(function.name(), var.id));
+            if is_predictable(&inputs) {
+                predictable_hashes.extend(outputs.clone());
+            }
+            hashes.extend(outputs);
+        }
+        let is_reduced = |var: &VarId| {
+            is_tainted(var)
+                && !taint
+                    .taints_any_sources(&hashes, &WrapperVariable::new(function.name(), var.id))
+        };
+
+        for (i, stmt) in function.get_statements().iter().enumerate() {
+            let pc = function.entry_point() + i;
+            let invoc = match stmt {
+                SierraStatement::Invocation(invoc) => invoc,
+                SierraStatement::Return(_) => continue,
+            };
+            let libfunc = compilation_unit
+                .registry()
+                .get_libfunc(&invoc.libfunc_id)
+                .expect("Library function not found in the registry");
+            let user_args =
+                || filter_builtins_from_arguments(libfunc.param_signatures(), invoc.args.clone());
+
+            match libfunc {
+                // The dividend of a modulo, an integer division uses the same libfunc but drops the remainder
+                CoreConcreteLibfunc::Uint8(UintConcrete::Divmod(_))
+                | CoreConcreteLibfunc::Uint16(UintConcrete::Divmod(_))
+                | CoreConcreteLibfunc::Uint32(UintConcrete::Divmod(_))
+                | CoreConcreteLibfunc::Uint64(UintConcrete::Divmod(_))
+                | CoreConcreteLibfunc::Uint128(Uint128Concrete::Divmod(_))
+                | CoreConcreteLibfunc::Uint256(Uint256Concrete::Divmod(_)) => {
+                    let is_remainder_used = invoc.branches[0]
+                        .results
+                        .get(REMAINDER_RESULT)
+                        .is_some_and(|var| {
+                            is_remainder_used(
+                                compilation_unit,
+                                function,
+                                &Value::new(var.clone(), Definition::Statement(pc)),
+                                false,
+                            )
+                        });
+                    if is_remainder_used && user_args().iter().any(is_reduced) {
+                        uses.insert(BlockInfoUse::Randomness);
+                    }
+                }
+                CoreConcreteLibfunc::Felt252(Felt252Concrete::IsZero(_))
+                | CoreConcreteLibfunc::Uint8(UintConcrete::Equal(_))
+                | CoreConcreteLibfunc::Uint16(UintConcrete::Equal(_))
+                | CoreConcreteLibfunc::Uint32(UintConcrete::Equal(_))
+                | CoreConcreteLibfunc::Uint64(UintConcrete::Equal(_))
+                | CoreConcreteLibfunc::Uint128(Uint128Concrete::Equal(_))
+                | CoreConcreteLibfunc::Sint8(SintConcrete::Equal(_))
+                | CoreConcreteLibfunc::Sint16(SintConcrete::Equal(_))
+                | CoreConcreteLibfunc::Sint32(SintConcrete::Equal(_))
+                | CoreConcreteLibfunc::Sint64(SintConcrete::Equal(_))
+                | CoreConcreteLibfunc::Sint128(Sint128Concrete::Equal(_))
+                    if invoc.args.iter().any(is_tainted) =>
+                {
+                    uses.insert(BlockInfoUse::StrictEquality);
+                }
+                CoreConcreteLibfunc::FunctionCall(f_called) => {
+                    let name = f_called.function.id.debug_name.as_ref().unwrap();
+                    let user_args = user_args();
+                    if !user_args.iter().any(is_tainted) {
+                        continue;
+                    }
+
+                    if is_modulo_function(name) {
+                        if user_args.iter().any(is_reduced) {
+                            uses.insert(BlockInfoUse::Randomness);
+                        }
+                    } else if is_div_rem_function(name) {
+                        // The quotient and the remainder are returned in a tuple
+                        if user_args.iter().any(is_reduced)
+                            && invoc.branches[0].results.last().is_some_and(|var| {
+                                is_remainder_used(
+                                    compilation_unit,
+                                    function,
+                                    &Value::new(var.clone(), Definition::Statement(pc)),
+                                    true,
+                                )
+                            })
+                        {
+                            uses.insert(BlockInfoUse::Randomness);
+                        }
+                    } else if name.ends_with("PartialEq::eq") || name.ends_with("PartialEq::ne") {
+                        uses.insert(BlockInfoUse::StrictEquality);
+                    }
+
+                    // Follow the values passed to private functions
+                    let private_function = match compilation_unit.function_by_name(name) {
+                        Some(f) if matches!(f.ty(), Type::Private | Type::Loop) => f,
+                        _ => continue,
+                    };
+                    if !checked_private_functions.insert(private_function.name()) {
+                        continue;
+                    }
+                    let private_sources: FxHashSet<WrapperVariable> = invoc
+                        .args
+                        .iter()
+                        .zip(private_function.params_all())
+                        .filter(|(arg, _)| is_tainted(arg))
+                        .map(|(_, param)| {
+                            WrapperVariable::new(private_function.name(), param.id.id)
+                        })
+                        .collect();
+                    self.find_uses(
+                        compilation_unit,
+                        private_function,
+                        &private_sources,
+                        checked_private_functions,
+                        uses,
+                    );
+                }
+                _ => (),
+            }
+        }
+
+        // A hash is a source of randomness once reduced with a modulo or deciding a branch
+        if predictable_hashes.is_empty() {
+            return;
+        }
+        let is_hash_used_as_randomness = function.get_statements().iter().any(|stmt| match stmt {
+            SierraStatement::Invocation(invoc) => {
+                let libfunc = compilation_unit
+                    .registry()
+                    .get_libfunc(&invoc.libfunc_id)
+                    .expect("Library function not found in the registry");
+                is_randomness_sink(libfunc)
+                    && invoc.args.iter().any(|arg| {
+                        taint.taints_any_sources(
+                            &predictable_hashes,
+                            &WrapperVariable::new(function.name(), arg.id),
+                        )
+                    })
+            }
+            SierraStatement::Return(_) => false,
+        });
+        if is_hash_used_as_randomness {
+            uses.insert(BlockInfoUse::Randomness);
+        }
+    }
+}
+
+/// Return true if the remainder is not only dropped
+/// When returned in a tuple with the quotient the remainder is the second member
+fn is_remainder_used(
+    compilation_unit: &CompilationUnit,
+    function: &Function,
+    value: &Value,
+    in_tuple: bool,
+) -> bool {
+    let registry = compilation_unit.registry();
+    if !in_tuple {
+        return !is_dropped(function, registry, value);
+    }
+
+    function
+        .def_use(registry)
+        .value_uses(value)
+        .into_iter()
+        .any(
+            |pc| match &function.get_statements()[pc - function.entry_point()] {
+                SierraStatement::Invocation(invoc) => match registry
+                    .get_libfunc(&invoc.libfunc_id)
+                    .expect("Library function not found in the registry")
+                {
+                    CoreConcreteLibfunc::Struct(StructConcreteLibfunc::Deconstruct(_)) => {
+                        invoc.branches[0].results.get(1).is_some_and(|var| {
+                            !is_dropped(
+                                function,
+                                registry,
+                                &Value::new(var.clone(), Definition::Statement(pc)),
+                            )
+                        })
+                    }
+                    CoreConcreteLibfunc::Drop(_) => false,
+                    _ => true,
+                },
+                SierraStatement::Return(_) => true,
+            },
+        )
+}
+
+/// Return true if the core function computes a modulo
+fn is_modulo_function(name: &str) -> bool {
+    name.starts_with("core::") && (name.ends_with("Rem::rem") || name.ends_with("RemEq::rem_eq"))
+}
+
+/// Return true if the core function computes both a division and a modulo
+fn is_div_rem_function(name: &str) -> bool {
+    name.starts_with("core::") && name.ends_with("DivRem::div_rem")
+}
+
+/// Return the user defined inputs and outputs of the hashes computed in the function
+fn hashes_computed(
+    compilation_unit: &CompilationUnit,
+    function: &Function,
+) -> Vec<(Vec<VarId>, Vec<VarId>)> {
+    function
+        .get_statements()
+        .iter()
+        .filter_map(|stmt| match stmt {
+            SierraStatement::Invocation(invoc) => Some(invoc),
+            SierraStatement::Return(_) => None,
+        })
+        .filter_map(|invoc| {
+            let libfunc = compilation_unit
+                .registry()
+                .get_libfunc(&invoc.libfunc_id)
+                .expect("Library function not found in the registry");
+            let is_hash = match libfunc {
+                CoreConcreteLibfunc::Pedersen(_)
+                | CoreConcreteLibfunc::Poseidon(_)
+                | CoreConcreteLibfunc::StarkNet(StarkNetConcreteLibfunc::Keccak(_)) => true,
+                CoreConcreteLibfunc::FunctionCall(f_called) => f_called
+                    .function
+                    .id
+                    .debug_name
+                    .as_ref()
+                    .is_some_and(|name| is_hash_function(name)),
+                _ => false,
+            };
+            is_hash.then(|| {
+                (
+                    filter_builtins_from_arguments(libfunc.param_signatures(), invoc.args.clone()),
+                    filter_builtins_from_returns(
+                        &libfunc.branch_signatures()[0].vars,
+                        invoc.branches[0].results.clone(),
+                    ),
+                )
+            })
+        })
+        .collect()
+}
+
+/// Return true if the core function computes a hash
+fn is_hash_function(name: &str) -> bool {
+    name.starts_with("core::pedersen::")
+        || name.starts_with("core::poseidon::")
+        || name.starts_with("core::keccak::")
+}
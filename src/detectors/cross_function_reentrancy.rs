@@ -0,0 +1,252 @@
+use super::detector::{Confidence, Detector, Impact, Result};
+use crate::analysis::dataflow::AnalysisState;
+use crate::analysis::reentrancy::ReentrancyDomain;
+use crate::analysis::taint::WrapperVariable;
+use crate::core::compilation_unit::CompilationUnit;
+use crate::core::core_unit::CoreUnit;
+use crate::core::function::{Function, Type};
+use crate::utils::{filter_builtins_from_arguments, is_panic_result_match};
+use cairo_lang_sierra::extensions::core::CoreConcreteLibfunc;
+use cairo_lang_sierra::extensions::ConcreteLibfunc;
+use cairo_lang_sierra::ids::VarId;
+use cairo_lang_sierra::program::GenStatement;
+use fxhash::FxHashSet;
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+#[derive(Default)]
+pub struct CrossFunctionReentrancy;
+
+impl Detector for CrossFunctionReentrancy {
+    fn name(&self) -> &str {
+        "cross-function-reentrancy"
+    }
+
+    fn description(&self) -> &str {
+        "Detect when a storage variable is written after an external call and another external function depends on its value"
+    }
+
+    fn confidence(&self) -> Confidence {
+        Confidence::Low
+    }
+
+    fn impact(&self) -> Impact {
+        Impact::Medium
+    }
+
+    fn run(&self, core: &CoreUnit) -> HashSet<Result> {
+        let mut results: HashSet<Result> = HashSet::new();
+        let compilation_units = core.get_compilation_units();
+
+        for compilation_unit in compilation_units {
+            // Key the storage variable read - Value the external functions name where its value is used
+            let mut vars_used: HashMap<String, HashSet<String>> = HashMap::new();
+
+            for f in compilation_unit
+                .functions_user_defined()
+                .filter(|f| f.ty() == &Type::External)
+            {
+                for function in compilation_unit
+                    .callgraph()
+                    .reachable_from(&f.name())
+                    .iter()
+                    .filter_map(|name| compilation_unit.function_by_name(name))
+                {
+                    for storage_var_read in function.storage_vars_read() {
+                        // The last value returned is the value read, the others are the builtins
+                        let read_values: FxHashSet<WrapperVariable> = match storage_var_read {
+                            GenStatement::Invocation(invoc) => invoc.branches[0]
+                                .results
+                                .last()
+                                .map(|var| WrapperVariable::new(function.name(), var.id))
+                                .into_iter()
+                                .collect(),
+                            GenStatement::Return(_) => continue,
+                        };
+                        if !self.depends_on(
+                            compilation_unit,
+                            function,
+                            &read_values,
+                            &mut HashSet::new(),
+                        ) {
+                            continue;
+                        }
+                        let var_used = storage_var_read
+                            .to_string()
+                            .rsplit_once("::")
+                            .unwrap()
+                            .0
+                            .to_string();
+                        vars_used.entry(var_used).or_default().insert(f.name());
+                    }
+                }
+            }
+
+            // Key (pair of functions in name order, variable) - Value the message
+            // only one result is reported for each variable used by a pair of functions
+            let mut reentrancies: HashMap<(String, String, String), String> = HashMap::new();
+
+            // The calls done by the private functions are in the summaries of the external functions calling them
+            for f in compilation_unit
+                .functions_user_defined()
+                .filter(|f| f.ty() == &Type::External)
+            {
+                for bb_info in f.analyses().reentrancy.iter() {
+                    if let AnalysisState {
+                        post: ReentrancyDomain::State(reentrancy_info),
+                        ..
+                    } = bb_info.1
+                    {
+                        for call in reentrancy_info.external_calls.iter() {
+                            let external_function_call =
+                                format!("{}", call.get_contract_call().unwrap().get_statement());
+
+                            if let Some(safe_external_calls) = core.get_safe_external_calls() {
+                                if safe_external_calls
+                                    .iter()
+                                    .any(|f_name| external_function_call.contains(f_name))
+                                {
+                                    continue;
+                                }
+                            }
+
+                            for written_variable in reentrancy_info.storage_variables_written.iter()
+                            {
+                                let written_variable_name = written_variable
+                                    .get_storage_variable_written()
+                                    .as_ref()
+                                    .unwrap()
+                                    .get_statement()
+                                    .to_string()
+                                    .rsplit_once("::")
+                                    .unwrap()
+                                    .0
+                                    .to_string();
+
+                                if let Some(external_functions) =
+                                    vars_used.get(&written_variable_name)
+                                {
+                                    // Reentering the same function is reported by the reentrancy detector
+                                    for external_function in
+                                        external_functions.iter().filter(|name| **name != f.name())
+                                    {
+                                        // The calls and writes done by the private and loop functions are reported in the external function
+                                        let message = format!(
+                                            "Cross function reentrancy in {} and {}\n\tExternal call {} done in {}\n\tVariable written after {} in {}\n\tVariable used in {}",
+                                            f.name(),
+                                            external_function,
+                                            external_function_call,
+                                            f.name(),
+                                            written_variable
+                                                .get_storage_variable_written()
+                                                .as_ref()
+                                                .unwrap()
+                                                .get_statement(),
+                                            f.name(),
+                                            external_function,
+                                        );
+                                        let pair = if f.name() < *external_function {
+                                            (f.name(), external_function.clone())
+                                        } else {
+                                            (external_function.clone(), f.name())
+                                        };
+                                        // Keep the smallest message to have the same result on each run
+                                        reentrancies
+                                            .entry((pair.0, pair.1, written_variable_name.clone()))
+                                            .and_modify(|reported| {
+                                                if message < *reported {
+                                                    *reported = message.clone();
+                                                }
+                                            })
+                                            .or_insert(message);
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+
+            results.extend(reentrancies.into_values().map(|message| Result {
+                name: self.name().to_string(),
+                impact: self.impact(),
+                confidence: self.confidence(),
+                message,
+            }));
+        }
+
+        results
+    }
+}
+
+impl CrossFunctionReentrancy {
+    /// Return true if the behaviour of the function depends on the values
+    /// i.e. they are used in a branch or written in storage, directly or in the functions called with them
+    fn depends_on(
+        &self,
+        compilation_unit: &CompilationUnit,
+        function: &Function,
+        values: &FxHashSet<WrapperVariable>,
+        visiting: &mut HashSet<String>,
+    ) -> bool {
+        let taint = match compilation_unit.get_taint(&function.name()) {
+            Some(taint) => taint,
+            None => return false,
+        };
+        if values.is_empty() || !visiting.insert(function.name()) {
+            return false;
+        }
+        let is_tainted = |var: &VarId| {
+            taint.taints_any_sources(values, &WrapperVariable::new(function.name(), var.id))
+        };
+
+        let depends = function.get_statements().iter().any(|stmt| {
+            let invoc = match stmt {
+                GenStatement::Invocation(invoc) => invoc,
+                GenStatement::Return(_) => return false,
+            };
+            let libfunc = compilation_unit
+                .registry()
+                .get_libfunc(&invoc.libfunc_id)
+                .expect("Library function not found in the registry");
+            // The builtins are passed along every call, they don't carry the values
+            let user_args =
+                filter_builtins_from_arguments(libfunc.param_signatures(), invoc.args.clone());
+            if !user_args.iter().any(is_tainted) {
+                return false;
+            }
+
+            match libfunc {
+                CoreConcreteLibfunc::FunctionCall(f_called) => {
+                    let called = match compilation_unit
+                        .function_by_name(f_called.function.id.debug_name.as_ref().unwrap())
+                    {
+                        Some(called) => called,
+                        None => return false,
+                    };
+                    match called.ty() {
+                        Type::Storage => called.name().ends_with("write"),
+                        Type::Private | Type::Loop => {
+                            // Map the arguments using the values to the parameters of the function called
+                            let params: FxHashSet<WrapperVariable> = invoc
+                                .args
+                                .iter()
+                                .zip(called.params_all())
+                                .filter(|(arg, _)| user_args.contains(arg) && is_tainted(arg))
+                                .map(|(_, param)| WrapperVariable::new(called.name(), param.id.id))
+                                .collect();
+                            self.depends_on(compilation_unit, called, &params, visiting)
+                        }
+                        _ => false,
+                    }
+                }
+                // Unwrapping the value read or sending it in a syscall that can fail is not a branch on it
+                CoreConcreteLibfunc::StarkNet(_) => false,
+                _ => invoc.branches.len() > 1 && !is_panic_result_match(libfunc),
+            }
+        });
+
+        visiting.remove(&function.name());
+        depends
+    }
+}
@@ -1,6 +1,8 @@
 use super::detector::{Confidence, Detector, Impact, Result};
+use crate::analysis::interval::{Interval, IntervalAnalysis};
 use crate::core::compilation_unit::CompilationUnit;
 use crate::core::core_unit::CoreUnit;
+use crate::core::function::Function;
 use cairo_lang_sierra::extensions::felt252::Felt252BinaryOperationConcrete;
 use cairo_lang_sierra::extensions::felt252::Felt252BinaryOperator;
 use cairo_lang_sierra::extensions::{core::CoreConcreteLibfunc, felt252::Felt252Concrete};
@@ -8,6 +10,7 @@ use cairo_lang_sierra::ids::VarId;
 use cairo_lang_sierra::program::Statement as SierraStatement;
 use cairo_lang_sierra::program::StatementIdx;
 use cairo_lang_sierra::program::{GenInvocation, GenStatement};
+use num_bigint::BigInt;
 use std::collections::HashSet;
 
 #[derive(Default)]
@@ -52,36 +55,36 @@ impl Detector for Felt252Overflow {
                         if let CoreConcreteLibfunc::Felt252(Felt252Concrete::BinaryOperation(op)) =
                             libfunc
                         {
-                            match op {
+                            let (operation, c) = match op {
                                 Felt252BinaryOperationConcrete::WithConst(var) => {
-                                    let operation = var.operator;
-
-                                    self.handle_binops(
-                                        &mut results,
-                                        compilation_unit,
-                                        invoc,
-                                        statements,
-                                        index,
-                                        stmt,
-                                        &operation,
-                                        &name,
-                                    )
+                                    (var.operator, Some(&var.c))
                                 }
                                 Felt252BinaryOperationConcrete::WithVar(var) => {
-                                    let operation = var.operator;
-
-                                    self.handle_binops(
-                                        &mut results,
-                                        compilation_unit,
-                                        invoc,
-                                        statements,
-                                        index,
-                                        stmt,
-                                        &operation,
-                                        &name,
-                                    )
+                                    (var.operator, None)
                                 }
+                            };
+
+                            if self.is_provably_safe(
+                                compilation_unit,
+                                f,
+                                f.entry_point() + index,
+                                invoc,
+                                &operation,
+                                c,
+                            ) {
+                                continue;
                             }
+
+                            self.handle_binops(
+                                &mut results,
+                                compilation_unit,
+                                invoc,
+                                statements,
+                                index,
+                                stmt,
+                                &operation,
+                                &name,
+                            )
                         }
                     }
                 }
@@ -176,6 +179,35 @@ impl Felt252Overflow {
         }
     }
 
+    // Return true if the interval analysis proves the result of the operation is in the felt252 range
+    fn is_provably_safe(
+        &self,
+        compilation_unit: &CompilationUnit,
+        function: &Function,
+        pc: usize,
+        invoc: &GenInvocation<StatementIdx>,
+        operation: &Felt252BinaryOperator,
+        c: Option<&BigInt>,
+    ) -> bool {
+        let state = IntervalAnalysis::state_before(function, pc, compilation_unit.registry());
+        let lhs = state.interval(&invoc.args[0]);
+        let rhs = match c {
+            Some(c) => Some(Interval::constant(c.clone())),
+            None => invoc.args.get(1).and_then(|a| state.interval(a)).cloned(),
+        };
+
+        if let (Some(lhs), Some(rhs)) = (lhs, rhs) {
+            let result = match operation {
+                Felt252BinaryOperator::Add => lhs.add(&rhs),
+                Felt252BinaryOperator::Sub => lhs.sub(&rhs),
+                Felt252BinaryOperator::Mul => lhs.mul(&rhs),
+                Felt252BinaryOperator::Div => return false,
+            };
+            return result.is_subset_of(&Interval::felt252());
+        }
+        false
+    }
+
     // Return true if there is not an is_zero op or if it does not use the ret_value
     // which means it is a real sub operation not used in an if
     fn check_is_zero(
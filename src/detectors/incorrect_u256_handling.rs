@@ -0,0 +1,275 @@
+use std::collections::HashSet;
+
+use super::detector::{Confidence, Detector, Impact, Result};
+use crate::analysis::def_use::{is_dropped, Definition, Value};
+use crate::analysis::panics::{always_panics_from, is_unwrapped};
+use crate::analysis::taint::WrapperVariable;
+use crate::core::cfg::Cfg;
+use crate::core::compilation_unit::CompilationUnit;
+use crate::core::core_unit::CoreUnit;
+use crate::core::function::{Function, Type};
+use crate::utils::{
+    branch_target, filter_builtins_from_arguments, is_narrowing_function, is_narrowing_libfunc,
+};
+use cairo_lang_sierra::extensions::core::CoreConcreteLibfunc;
+use cairo_lang_sierra::extensions::enm::EnumConcreteLibfunc;
+use cairo_lang_sierra::extensions::structure::StructConcreteLibfunc;
+use cairo_lang_sierra::extensions::ConcreteLibfunc;
+use cairo_lang_sierra::ids::VarId;
+use cairo_lang_sierra::program::GenStatement;
+use fxhash::FxHashSet;
+
+const U256_TYPE: &str = "core::integer::u256";
+
+#[derive(Default)]
+pub struct IncorrectU256Handling {}
+
+impl Detector for IncorrectU256Handling {
+    fn name(&self) -> &str {
+        "incorrect-u256-handling"
+    }
+
+    fn description(&self) -> &str {
+        "Detect user controlled u256 split in low and high with the high part ignored or built from a felt252 conversion that can silently fail"
+    }
+
+    fn confidence(&self) -> Confidence {
+        Confidence::Low
+    }
+
+    fn impact(&self) -> Impact {
+        Impact::Medium
+    }
+
+    fn run(&self, core: &CoreUnit) -> HashSet<Result> {
+        let mut results: HashSet<Result> = HashSet::new();
+        let compilation_units = core.get_compilation_units();
+
+        for compilation_unit in compilation_units {
+            for f in compilation_unit.functions_user_defined() {
+                let unchecked_conversions = unchecked_felt252_conversions(compilation_unit, f);
+
+                for (i, stmt) in f.get_statements().iter().enumerate() {
+                    let pc = f.entry_point() + i;
+                    let invoc = match stmt {
+                        GenStatement::Invocation(invoc) => invoc,
+                        GenStatement::Return(_) => continue,
+                    };
+
+                    let message = match compilation_unit
+                        .registry()
+                        .get_libfunc(&invoc.libfunc_id)
+                        .expect("Library function not found in the registry")
+                    {
+                        // The u256 is split in low and high, the high part must not be ignored
+                        CoreConcreteLibfunc::Struct(StructConcreteLibfunc::Deconstruct(s))
+                            if s.signature.param_signatures[0].ty.debug_name.as_deref()
+                                == Some(U256_TYPE) =>
+                        {
+                            match invoc.branches[0].results.get(1) {
+                                Some(high)
+                                    if compilation_unit
+                                        .is_tainted(f.name(), invoc.args[0].clone())
+                                        && is_dropped(
+                                            f,
+                                            compilation_unit.registry(),
+                                            &Value::new(high.clone(), Definition::Statement(pc)),
+                                        ) =>
+                                {
+                                    format!(
+                                        "The high part of a user controlled u256 is dropped without checking it is zero in {}",
+                                        f.name()
+                                    )
+                                }
+                                _ => continue,
+                            }
+                        }
+                        // The u256 is built from low and high, they must not default to another value
+                        CoreConcreteLibfunc::Struct(StructConcreteLibfunc::Construct(s))
+                            if s.signature.branch_signatures[0].vars[0]
+                                .ty
+                                .debug_name
+                                .as_deref()
+                                == Some(U256_TYPE) =>
+                        {
+                            let taint = match compilation_unit.get_taint(&f.name()) {
+                                Some(taint) => taint,
+                                None => continue,
+                            };
+                            let is_unchecked =
+                                unchecked_conversions
+                                    .iter()
+                                    .any(|(conversion, failure_pc)| {
+                                        is_reachable(f, *failure_pc, pc)
+                                            && invoc.args.iter().any(|arg| {
+                                                taint.taints_any_sources(
+                                                    &FxHashSet::from_iter([conversion.clone()]),
+                                                    &WrapperVariable::new(f.name(), arg.id),
+                                                )
+                                            })
+                                    });
+                            if !is_unchecked {
+                                continue;
+                            }
+                            format!(
+                                "A u256 is built in {} from a user controlled felt252 converted without checking it fits in a u128",
+                                f.name()
+                            )
+                        }
+                        _ => continue,
+                    };
+
+                    results.insert(Result {
+                        name: self.name().to_string(),
+                        impact: self.impact(),
+                        confidence: self.confidence(),
+                        message,
+                    });
+                }
+            }
+        }
+
+        results
+    }
+}
+
+/// Return the conversions of the user controlled felt252 parameters of an entry point that don't panic on failure
+/// with the pc where the execution continues when the value doesn't fit
+fn unchecked_felt252_conversions(
+    compilation_unit: &CompilationUnit,
+    function: &Function,
+) -> Vec<(WrapperVariable, usize)> {
+    let mut conversions = Vec::new();
+    if !matches!(function.ty(), Type::External | Type::View | Type::L1Handler) {
+        return conversions;
+    }
+    let taint = match compilation_unit.get_taint(&function.name()) {
+        Some(taint) => taint,
+        None => return conversions,
+    };
+    // The first parameter is the contract state
+    let felt252_params: FxHashSet<WrapperVariable> = function
+        .params()
+        .skip(1)
+        .filter(|param| param.ty.debug_name.as_deref() == Some("felt252"))
+        .map(|param| WrapperVariable::new(function.name(), param.id.id))
+        .collect();
+    if felt252_params.is_empty() {
+        return conversions;
+    }
+
+    let registry = compilation_unit.registry();
+    for (i, stmt) in function.get_statements().iter().enumerate() {
+        let pc = function.entry_point() + i;
+        let invoc = match stmt {
+            GenStatement::Invocation(invoc) => invoc,
+            GenStatement::Return(_) => continue,
+        };
+        let libfunc = registry
+            .get_libfunc(&invoc.libfunc_id)
+            .expect("Library function not found in the registry");
+
+        let (param_signatures, result) = match libfunc {
+            _ if is_narrowing_libfunc(libfunc) => {
+                (libfunc.param_signatures(), invoc.branches[0].results.last())
+            }
+            CoreConcreteLibfunc::FunctionCall(f_called)
+                if is_narrowing_function(f_called.function.id.debug_name.as_ref().unwrap()) =>
+            {
+                (
+                    f_called.signature.param_signatures.as_slice(),
+                    invoc.branches[0].results.last(),
+                )
+            }
+            _ => continue,
+        };
+        let (value, result) = match (
+            filter_builtins_from_arguments(param_signatures, invoc.args.clone()).first(),
+            result,
+        ) {
+            (Some(value), Some(result)) => (value.clone(), result.clone()),
+            _ => continue,
+        };
+        if !taint.taints_any_sources(
+            &felt252_params,
+            &WrapperVariable::new(function.name(), value.id),
+        ) {
+            continue;
+        }
+
+        // The libfunc branches on failure, the core function returns an Option
+        let failure_pcs: Vec<usize> = if invoc.branches.len() > 1 {
+            invoc.branches[1..]
+                .iter()
+                .map(|branch| branch_target(branch, pc))
+                .collect()
+        } else if is_unwrapped(compilation_unit, function, &result, pc) {
+            continue;
+        } else {
+            none_branches(compilation_unit, function, &result, pc)
+        };
+        conversions.extend(
+            failure_pcs
+                .into_iter()
+                .filter(|failure_pc| !always_panics_from(function, registry, *failure_pc))
+                .map(|failure_pc| (WrapperVariable::new(function.name(), result.id), failure_pc)),
+        );
+    }
+
+    conversions
+}
+
+/// Return the pc where the execution continues when the Option held by the variable defined at pc is None
+fn none_branches(
+    compilation_unit: &CompilationUnit,
+    function: &Function,
+    option: &VarId,
+    pc: usize,
+) -> Vec<usize> {
+    let registry = compilation_unit.registry();
+    function
+        .def_use(registry)
+        .value_uses(&Value::new(option.clone(), Definition::Statement(pc)))
+        .into_iter()
+        .filter_map(|pc| {
+            match &function.get_statements()[pc - function.entry_point()] {
+                GenStatement::Invocation(invoc) => match registry
+                    .get_libfunc(&invoc.libfunc_id)
+                    .expect("Library function not found in the registry")
+                {
+                    // The None variant is the second one
+                    CoreConcreteLibfunc::Enum(EnumConcreteLibfunc::Match(_)) => {
+                        invoc.branches.get(1).map(|none| branch_target(none, pc))
+                    }
+                    _ => None,
+                },
+                GenStatement::Return(_) => None,
+            }
+        })
+        .collect()
+}
+
+/// Return true if the instruction at to is reachable from the instruction at from
+fn is_reachable(function: &Function, from: usize, to: usize) -> bool {
+    let cfg = function.get_cfg();
+    let (start, end) = match (function.basic_block_at(from), function.basic_block_at(to)) {
+        (Some(start), Some(end)) => (start, end),
+        _ => return false,
+    };
+
+    let mut visited = HashSet::new();
+    let mut worklist = vec![start];
+    while let Some(bb_id) = worklist.pop() {
+        if bb_id == end {
+            return true;
+        }
+        if !visited.insert(bb_id) {
+            continue;
+        }
+        if let Some(bb) = cfg.get_basic_block(bb_id) {
+            worklist.extend(bb.get_outgoing_basic_blocks().iter().copied());
+        }
+    }
+
+    false
+}
@@ -0,0 +1,221 @@
+use std::collections::BTreeSet;
+use std::collections::HashSet;
+
+use super::detector::{Confidence, Detector, Impact, Result};
+use crate::analysis::caller_check::{authorization_storage_vars, is_protected_by_caller_check};
+use crate::analysis::initializer::{is_guarded_by_flag, is_initializer};
+use crate::core::compilation_unit::CompilationUnit;
+use crate::core::core_unit::CoreUnit;
+use crate::core::function::{Function, Type};
+use crate::utils::{called_function_name, storage_var_name};
+use cairo_lang_sierra::extensions::core::CoreConcreteLibfunc;
+use cairo_lang_sierra::extensions::starknet::StarkNetConcreteLibfunc;
+use cairo_lang_sierra::program::GenStatement;
+
+/// Privileged operations known by the detector, the other sinks provided by the user are function names
+/// authorization-write is a write to a storage variable compared with the caller address e.g. the owner
+const KNOWN_PRIVILEGED_SINKS: [&str; 4] = [
+    "storage-write",
+    "authorization-write",
+    "replace-class",
+    "external-call",
+];
+
+/// Privileged operations checked when the user doesn't provide them
+/// The upgrades are reported by the unprotected-upgrade detector
+const DEFAULT_PRIVILEGED_SINKS: [&str; 2] = ["storage-write", "external-call"];
+
+#[derive(Default)]
+pub struct MissingAccessControl {}
+
+impl Detector for MissingAccessControl {
+    fn name(&self) -> &str {
+        "missing-access-control"
+    }
+
+    fn description(&self) -> &str {
+        "Detect external functions doing privileged operations without checking the caller address"
+    }
+
+    fn confidence(&self) -> Confidence {
+        Confidence::Low
+    }
+
+    fn impact(&self) -> Impact {
+        Impact::High
+    }
+
+    fn run(&self, core: &CoreUnit) -> HashSet<Result> {
+        let mut results: HashSet<Result> = HashSet::new();
+        let compilation_units = core.get_compilation_units();
+
+        let privileged_sinks: Vec<String> = match core.get_privileged_sinks() {
+            Some(sinks) => sinks.clone(),
+            None => DEFAULT_PRIVILEGED_SINKS
+                .iter()
+                .map(|s| s.to_string())
+                .collect(),
+        };
+
+        for compilation_unit in compilation_units {
+            let authorization_vars = authorization_storage_vars(compilation_unit);
+
+            for f in compilation_unit
+                .functions_user_defined()
+                .filter(|f| *f.ty() == Type::External)
+            {
+                // An initializer asserting its initialized flag can be called only once
+                if is_initializer(&f.name()) && is_guarded_by_flag(compilation_unit, f) {
+                    continue;
+                }
+
+                // The privileged operations done by the function or the functions it calls
+                // that are not always executed after a caller check
+                let sinks_reached: BTreeSet<String> = self
+                    .unprotected_operations(
+                        compilation_unit,
+                        f,
+                        &privileged_sinks,
+                        &authorization_vars,
+                        &mut HashSet::new(),
+                    )
+                    .into_iter()
+                    .collect();
+
+                if sinks_reached.is_empty() {
+                    continue;
+                }
+
+                let message = format!(
+                    "The function {} does privileged operations without checking the caller address: {}",
+                    f.name(),
+                    sinks_reached.into_iter().collect::<Vec<String>>().join(", ")
+                );
+                results.insert(Result {
+                    name: self.name().to_string(),
+                    impact: self.impact(),
+                    confidence: self.confidence(),
+                    message,
+                });
+            }
+        }
+
+        results
+    }
+}
+
+impl MissingAccessControl {
+    /// Return the privileged operations reachable from the function without a caller check before
+    /// A function called is followed only when the call itself is not protected
+    fn unprotected_operations(
+        &self,
+        compilation_unit: &CompilationUnit,
+        function: &Function,
+        privileged_sinks: &[String],
+        authorization_vars: &HashSet<String>,
+        visited: &mut HashSet<String>,
+    ) -> Vec<String> {
+        let mut operations = Vec::new();
+        if !visited.insert(function.name()) {
+            return operations;
+        }
+
+        for (operation, pc) in self.privileged_operations(
+            compilation_unit,
+            function,
+            privileged_sinks,
+            authorization_vars,
+        ) {
+            if !is_protected_by_caller_check(compilation_unit, function, pc) {
+                operations.push(operation);
+            }
+        }
+
+        let callees: HashSet<&String> = compilation_unit
+            .callgraph()
+            .callees(&function.name())
+            .collect();
+        for (i, stmt) in function.get_statements().iter().enumerate() {
+            let called = match called_function_name(compilation_unit.registry(), stmt)
+                .filter(|name| callees.contains(name))
+                .and_then(|name| compilation_unit.function_by_name(&name))
+            {
+                Some(called) => called,
+                None => continue,
+            };
+            if !is_protected_by_caller_check(compilation_unit, function, function.entry_point() + i)
+            {
+                operations.extend(self.unprotected_operations(
+                    compilation_unit,
+                    called,
+                    privileged_sinks,
+                    authorization_vars,
+                    visited,
+                ));
+            }
+        }
+
+        operations
+    }
+
+    /// Return the privileged operations done directly in the function with their pc
+    fn privileged_operations(
+        &self,
+        compilation_unit: &CompilationUnit,
+        function: &Function,
+        privileged_sinks: &[String],
+        authorization_vars: &HashSet<String>,
+    ) -> Vec<(String, usize)> {
+        let mut operations = Vec::new();
+
+        for (i, stmt) in function.get_statements().iter().enumerate() {
+            let pc = function.entry_point() + i;
+            if let GenStatement::Invocation(invoc) = stmt {
+                let libfunc = compilation_unit
+                    .registry()
+                    .get_libfunc(&invoc.libfunc_id)
+                    .expect("Library function not found in the registry");
+
+                match libfunc {
+                    CoreConcreteLibfunc::StarkNet(StarkNetConcreteLibfunc::ReplaceClass(_))
+                        if privileged_sinks.iter().any(|s| s == "replace-class") =>
+                    {
+                        operations.push(("replace_class_syscall".to_string(), pc));
+                    }
+                    CoreConcreteLibfunc::StarkNet(StarkNetConcreteLibfunc::CallContract(_))
+                        if privileged_sinks.iter().any(|s| s == "external-call") =>
+                    {
+                        operations.push(("call_contract_syscall".to_string(), pc));
+                    }
+                    CoreConcreteLibfunc::FunctionCall(f_called) => {
+                        let name = f_called.function.id.debug_name.as_ref().unwrap();
+                        let is_sink = match compilation_unit.function_by_name(name).map(|f| f.ty())
+                        {
+                            Some(Type::Storage) if name.ends_with("write") => {
+                                privileged_sinks.iter().any(|s| s == "storage-write")
+                                    || (privileged_sinks.iter().any(|s| s == "authorization-write")
+                                        && authorization_vars.contains(storage_var_name(name)))
+                            }
+                            Some(Type::AbiCallContract) => {
+                                privileged_sinks.iter().any(|s| s == "external-call")
+                            }
+                            _ => false,
+                        };
+                        // User provided sinks are matched against the name of the functions called
+                        if is_sink
+                            || privileged_sinks
+                                .iter()
+                                .filter(|s| !KNOWN_PRIVILEGED_SINKS.contains(&s.as_str()))
+                                .any(|s| name.contains(s.as_str()))
+                        {
+                            operations.push((name.to_string(), pc));
+                        }
+                    }
+                    _ => (),
+                }
+            }
+        }
+
+        operations
+    }
+}
@@ -0,0 +1,126 @@
+use std::collections::{BTreeSet, HashSet};
+
+use super::detector::{Confidence, Detector, Impact, Result};
+use crate::analysis::caller_check::has_caller_check;
+use crate::core::compilation_unit::CompilationUnit;
+use crate::core::core_unit::CoreUnit;
+use crate::core::function::{Function, Type};
+use crate::utils::called_function_name;
+
+#[derive(Default)]
+pub struct MissingEvents {}
+
+impl Detector for MissingEvents {
+    fn name(&self) -> &str {
+        "missing-events"
+    }
+
+    fn description(&self) -> &str {
+        "Detect access controlled functions writing the storage without emitting an event"
+    }
+
+    fn confidence(&self) -> Confidence {
+        Confidence::Medium
+    }
+
+    fn impact(&self) -> Impact {
+        Impact::Informational
+    }
+
+    fn run(&self, core: &CoreUnit) -> HashSet<Result> {
+        let mut results: HashSet<Result> = HashSet::new();
+        let compilation_units = core.get_compilation_units();
+
+        for compilation_unit in compilation_units {
+            for f in compilation_unit
+                .functions_user_defined()
+                .filter(|f| *f.ty() == Type::External)
+                .filter(|f| has_caller_check(compilation_unit, f))
+            {
+                let storage_vars_written =
+                    writes_without_event(compilation_unit, f, &mut HashSet::new());
+                if storage_vars_written.is_empty() {
+                    continue;
+                }
+
+                let message = format!(
+                    "The function {} changes the state without emitting an event: {}",
+                    f.name(),
+                    storage_vars_written
+                        .into_iter()
+                        .collect::<Vec<String>>()
+                        .join(", ")
+                );
+                results.insert(Result {
+                    name: self.name().to_string(),
+                    impact: self.impact(),
+                    confidence: self.confidence(),
+                    message,
+                });
+            }
+        }
+
+        results
+    }
+}
+
+/// Return the storage variables written by the function, directly or in the functions it calls,
+/// without emitting an event after the write
+fn writes_without_event(
+    compilation_unit: &CompilationUnit,
+    function: &Function,
+    visiting: &mut HashSet<String>,
+) -> BTreeSet<String> {
+    visiting.insert(function.name());
+
+    // The pc of the storage writes and of the calls writing the storage with the variables written
+    let mut writes: Vec<(usize, BTreeSet<String>)> = Vec::new();
+    // The pc of the events emitted and of the calls emitting an event
+    let mut events: Vec<usize> = Vec::new();
+    for (i, stmt) in function.get_statements().iter().enumerate() {
+        let pc = function.entry_point() + i;
+        let called = match called_function_name(compilation_unit.registry(), stmt)
+            .and_then(|name| compilation_unit.function_by_name(&name))
+        {
+            Some(called) => called,
+            None => continue,
+        };
+
+        match called.ty() {
+            Type::Storage if called.name().ends_with("write") => {
+                writes.push((pc, BTreeSet::from([called.name()])));
+            }
+            Type::Event => events.push(pc),
+            Type::Private | Type::Loop | Type::External | Type::View
+                if !visiting.contains(&called.name()) =>
+            {
+                let written = writes_without_event(compilation_unit, called, visiting);
+                if !written.is_empty() {
+                    writes.push((pc, written));
+                }
+                if compilation_unit
+                    .callgraph()
+                    .reachable_from(&called.name())
+                    .iter()
+                    .filter_map(|name| compilation_unit.function_by_name(name))
+                    .any(|reached| reached.events_emitted().next().is_some())
+                {
+                    events.push(pc);
+                }
+            }
+            _ => (),
+        }
+    }
+
+    visiting.remove(&function.name());
+    // An event dominated by the write, i.e. emitted only after it, reports the write
+    writes
+        .into_iter()
+        .filter(|(write, _)| {
+            !events
+                .iter()
+                .any(|event| event != write && function.instruction_dominates(*write, *event))
+        })
+        .flat_map(|(_, written)| written)
+        .collect()
+}
@@ -0,0 +1,208 @@
+use std::collections::{BTreeSet, HashSet};
+
+use super::detector::{Confidence, Detector, Impact, Result};
+use crate::analysis::caller_check::address_zero_check;
+use crate::analysis::taint::WrapperVariable;
+use crate::core::compilation_unit::CompilationUnit;
+use crate::core::core_unit::CoreUnit;
+use crate::core::function::{Function, Type};
+use crate::utils::{branch_target, filter_builtins_from_arguments};
+use cairo_lang_sierra::extensions::core::CoreConcreteLibfunc;
+use cairo_lang_sierra::ids::VarId;
+use cairo_lang_sierra::program::GenStatement;
+use fxhash::FxHashSet;
+
+const CONTRACT_ADDRESS_TYPES: [&str; 2] = [
+    "ContractAddress",
+    "core::starknet::contract_address::ContractAddress",
+];
+
+#[derive(Default)]
+pub struct MissingZeroAddressCheck {}
+
+impl Detector for MissingZeroAddressCheck {
+    fn name(&self) -> &str {
+        "missing-zero-address-check"
+    }
+
+    fn description(&self) -> &str {
+        "Detect address parameters written to the storage without checking they are not zero"
+    }
+
+    fn confidence(&self) -> Confidence {
+        Confidence::Medium
+    }
+
+    fn impact(&self) -> Impact {
+        Impact::Low
+    }
+
+    fn run(&self, core: &CoreUnit) -> HashSet<Result> {
+        let mut results: HashSet<Result> = HashSet::new();
+        let compilation_units = core.get_compilation_units();
+
+        for compilation_unit in compilation_units {
+            for f in compilation_unit
+                .functions_user_defined()
+                .filter(|f| matches!(f.ty(), Type::External | Type::Constructor))
+            {
+                // Each address is checked separately, a check on one address doesn't validate the others
+                let mut storage_writes = BTreeSet::new();
+                for address in f.params().filter(|param| {
+                    param
+                        .ty
+                        .debug_name
+                        .as_ref()
+                        .is_some_and(|ty| CONTRACT_ADDRESS_TYPES.contains(&ty.as_str()))
+                }) {
+                    unchecked_writes(
+                        compilation_unit,
+                        f,
+                        &FxHashSet::from_iter([WrapperVariable::new(f.name(), address.id.id)]),
+                        &mut HashSet::new(),
+                        &mut storage_writes,
+                    );
+                }
+
+                for storage_write in storage_writes {
+                    let message = format!(
+                        "The function {} writes a user provided address with {} without checking it is not zero",
+                        f.name(),
+                        storage_write
+                    );
+                    results.insert(Result {
+                        name: self.name().to_string(),
+                        impact: self.impact(),
+                        confidence: self.confidence(),
+                        message,
+                    });
+                }
+            }
+        }
+
+        results
+    }
+}
+
+/// Find the storage write functions called with a value derived from the addresses without a zero check
+/// in the function and in the private functions it calls
+fn unchecked_writes(
+    compilation_unit: &CompilationUnit,
+    function: &Function,
+    addresses: &FxHashSet<WrapperVariable>,
+    checked_private_functions: &mut HashSet<String>,
+    storage_writes: &mut BTreeSet<String>,
+) {
+    let taint = match compilation_unit.get_taint(&function.name()) {
+        Some(taint) => taint,
+        None => return,
+    };
+    let is_tainted = |var: &VarId| {
+        taint.taints_any_sources(addresses, &WrapperVariable::new(function.name(), var.id))
+    };
+
+    for (i, stmt) in function.get_statements().iter().enumerate() {
+        let pc = function.entry_point() + i;
+        let invoc = match stmt {
+            GenStatement::Invocation(invoc) => invoc,
+            GenStatement::Return(_) => continue,
+        };
+        let f_called = match compilation_unit
+            .registry()
+            .get_libfunc(&invoc.libfunc_id)
+            .expect("Library function not found in the registry")
+        {
+            CoreConcreteLibfunc::FunctionCall(f_called) => f_called,
+            _ => continue,
+        };
+        let name = f_called.function.id.debug_name.as_ref().unwrap();
+        let called = match compilation_unit.function_by_name(name) {
+            Some(called) => called,
+            None => continue,
+        };
+
+        match called.ty() {
+            // The value written is the last argument, the contract state can be tainted by previous writes
+            // and the addresses used as keys of a mapping are not stored addresses
+            Type::Storage if name.ends_with("write") => {
+                let is_address_written = f_called
+                    .signature
+                    .param_signatures
+                    .last()
+                    .and_then(|param| param.ty.debug_name.as_ref())
+                    .is_some_and(|ty| CONTRACT_ADDRESS_TYPES.contains(&ty.as_str()));
+                if is_address_written
+                    && invoc.args.last().is_some_and(is_tainted)
+                    && !is_zero_checked(compilation_unit, function, addresses, pc)
+                {
+                    storage_writes.insert(name.to_string());
+                }
+            }
+            // Follow the addresses passed to private functions when they are not checked before the call
+            Type::Private | Type::Loop => {
+                if is_zero_checked(compilation_unit, function, addresses, pc)
+                    || !checked_private_functions.insert(called.name())
+                {
+                    continue;
+                }
+                let user_args = filter_builtins_from_arguments(
+                    &f_called.signature.param_signatures,
+                    invoc.args.clone(),
+                );
+                let private_addresses: FxHashSet<WrapperVariable> = user_args
+                    .iter()
+                    .zip(called.params())
+                    .filter(|(arg, _)| is_tainted(arg))
+                    .map(|(_, param)| WrapperVariable::new(called.name(), param.id.id))
+                    .collect();
+                if !private_addresses.is_empty() {
+                    unchecked_writes(
+                        compilation_unit,
+                        called,
+                        &private_addresses,
+                        checked_private_functions,
+                        storage_writes,
+                    );
+                }
+            }
+            _ => (),
+        }
+    }
+}
+
+/// Return true if the instruction at pc is only executed when one of the addresses is not zero
+/// i.e. it's dominated by the non-zero branch of a zero check of the address
+/// e.g. assert(!address.is_zero()) or if address.is_non_zero() { ... }
+fn is_zero_checked(
+    compilation_unit: &CompilationUnit,
+    function: &Function,
+    addresses: &FxHashSet<WrapperVariable>,
+    pc: usize,
+) -> bool {
+    let taint = match compilation_unit.get_taint(&function.name()) {
+        Some(taint) => taint,
+        None => return false,
+    };
+    let is_tainted = |var: &VarId| {
+        taint.taints_any_sources(addresses, &WrapperVariable::new(function.name(), var.id))
+    };
+
+    function
+        .get_statements()
+        .iter()
+        .enumerate()
+        .any(|(i, stmt)| {
+            let check_pc = function.entry_point() + i;
+            let invoc = match stmt {
+                GenStatement::Invocation(invoc) => invoc,
+                GenStatement::Return(_) => return false,
+            };
+            match address_zero_check(compilation_unit, function, check_pc, &is_tainted) {
+                Some(non_zero_branch) => function.instruction_dominates(
+                    branch_target(&invoc.branches[non_zero_branch], check_pc),
+                    pc,
+                ),
+                None => false,
+            }
+        })
+}
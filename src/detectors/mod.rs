@@ -1,19 +1,43 @@
 use self::detector::Detector;
 
+pub mod account_execute;
+pub mod account_validate;
+pub mod block_info_dependence;
 pub mod controlled_library_call;
+pub mod cross_function_reentrancy;
 pub mod dead_code;
 pub mod detector;
 pub mod felt252_overflow;
+pub mod incorrect_u256_handling;
+pub mod missing_access_control;
+pub mod missing_events;
+pub mod missing_zero_address_check;
 pub mod read_only_reentrancy;
 pub mod reentrancy;
 pub mod reentrancy_benign;
 pub mod reentrancy_events;
+pub mod signature_replay;
+pub mod state_change_after_l1_message;
+pub mod storage_collision;
 pub mod tx_origin;
+pub mod unbounded_loop;
+pub mod unchecked_conversion;
+pub mod unchecked_division;
 pub mod unchecked_l1_handler_from;
+pub mod unchecked_l1_message_address;
+pub mod unchecked_l1_message_payload;
+pub mod unchecked_signature;
+pub mod unchecked_transfer;
+pub mod unenforced_view;
+pub mod unguarded_initializer;
+pub mod unprotected_upgrade;
+pub mod unsafe_deploy;
 pub mod unused_arguments;
 pub mod unused_events;
 pub mod unused_return;
 pub mod use_after_pop_front;
+pub mod view_panic;
+pub mod weak_randomness;
 
 pub fn get_detectors() -> Vec<Box<dyn Detector>> {
     vec![
@@ -30,5 +54,29 @@ pub fn get_detectors() -> Vec<Box<dyn Detector>> {
         Box::<unchecked_l1_handler_from::UncheckedL1HandlerFrom>::default(),
         Box::<felt252_overflow::Felt252Overflow>::default(),
         Box::<tx_origin::TxOrigin>::default(),
+        Box::<missing_access_control::MissingAccessControl>::default(),
+        Box::<unprotected_upgrade::UnprotectedUpgrade>::default(),
+        Box::<unchecked_conversion::UncheckedConversion>::default(),
+        Box::<unchecked_division::UncheckedDivision>::default(),
+        Box::<unchecked_signature::UncheckedSignature>::default(),
+        Box::<signature_replay::SignatureReplay>::default(),
+        Box::<unbounded_loop::UnboundedLoop>::default(),
+        Box::<unenforced_view::UnenforcedView>::default(),
+        Box::<unchecked_l1_message_address::UncheckedL1MessageAddress>::default(),
+        Box::<unchecked_l1_message_payload::UncheckedL1MessagePayload>::default(),
+        Box::<state_change_after_l1_message::StateChangeAfterL1Message>::default(),
+        Box::<unchecked_transfer::UncheckedTransfer>::default(),
+        Box::<storage_collision::StorageCollision>::default(),
+        Box::<cross_function_reentrancy::CrossFunctionReentrancy>::default(),
+        Box::<block_info_dependence::BlockInfoDependence>::default(),
+        Box::<weak_randomness::WeakRandomness>::default(),
+        Box::<unsafe_deploy::UnsafeDeploy>::default(),
+        Box::<missing_zero_address_check::MissingZeroAddressCheck>::default(),
+        Box::<missing_events::MissingEvents>::default(),
+        Box::<view_panic::ViewPanic>::default(),
+        Box::<incorrect_u256_handling::IncorrectU256Handling>::default(),
+        Box::<account_validate::AccountValidate>::default(),
+        Box::<account_execute::AccountExecute>::default(),
+        Box::<unguarded_initializer::UnguardedInitializer>::default(),
     ]
 }
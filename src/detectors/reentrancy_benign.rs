@@ -38,10 +38,8 @@ impl Detector for ReentrancyBenign {
                     } = bb_info.1
                     {
                         for call in reentrancy_info.external_calls.iter() {
-                            let external_function_call = format!(
-                                "{}",
-                                call.get_external_call().as_ref().unwrap().get_statement()
-                            );
+                            let external_function_call =
+                                format!("{}", call.get_contract_call().unwrap().get_statement());
 
                             if let Some(safe_external_calls) = core.get_safe_external_calls() {
                                 if safe_external_calls
@@ -0,0 +1,60 @@
+use std::collections::HashSet;
+
+use super::detector::{Confidence, Detector, Impact, Result};
+use crate::analysis::signature::{is_replay_protected, signature_verifications};
+use crate::core::core_unit::CoreUnit;
+use crate::core::function::Type;
+
+#[derive(Default)]
+pub struct SignatureReplay {}
+
+impl Detector for SignatureReplay {
+    fn name(&self) -> &str {
+        "signature-replay"
+    }
+
+    fn description(&self) -> &str {
+        "Detect signatures verified on a message without a nonce or chain id"
+    }
+
+    fn confidence(&self) -> Confidence {
+        Confidence::Low
+    }
+
+    fn impact(&self) -> Impact {
+        Impact::High
+    }
+
+    fn run(&self, core: &CoreUnit) -> HashSet<Result> {
+        let mut results: HashSet<Result> = HashSet::new();
+        let compilation_units = core.get_compilation_units();
+
+        for compilation_unit in compilation_units {
+            // A view function can't change the state, replaying the signature has no effect
+            for f in compilation_unit
+                .functions_user_defined()
+                .filter(|f| *f.ty() != Type::View)
+            {
+                for verification in signature_verifications(compilation_unit, f) {
+                    if is_replay_protected(compilation_unit, f, &verification) {
+                        continue;
+                    }
+
+                    let message = format!(
+                        "The message verified with {} in {} doesn't depend on a nonce or the chain id, the signature can be replayed",
+                        verification.name,
+                        f.name()
+                    );
+                    results.insert(Result {
+                        name: self.name().to_string(),
+                        impact: self.impact(),
+                        confidence: self.confidence(),
+                        message,
+                    });
+                }
+            }
+        }
+
+        results
+    }
+}
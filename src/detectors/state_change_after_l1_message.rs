@@ -0,0 +1,135 @@
+use std::collections::{BTreeSet, HashSet};
+
+use super::detector::{Confidence, Detector, Impact, Result};
+use crate::analysis::l1_message::messages_to_l1;
+use crate::core::compilation_unit::CompilationUnit;
+use crate::core::core_unit::CoreUnit;
+use crate::core::function::Type;
+use cairo_lang_sierra::extensions::core::CoreConcreteLibfunc;
+use cairo_lang_sierra::program::GenStatement;
+
+#[derive(Default)]
+pub struct StateChangeAfterL1Message {}
+
+impl Detector for StateChangeAfterL1Message {
+    fn name(&self) -> &str {
+        "state-change-after-l1-message"
+    }
+
+    fn description(&self) -> &str {
+        "Detect storage variables written after a message is sent to L1"
+    }
+
+    fn confidence(&self) -> Confidence {
+        Confidence::Medium
+    }
+
+    fn impact(&self) -> Impact {
+        Impact::Low
+    }
+
+    fn run(&self, core: &CoreUnit) -> HashSet<Result> {
+        let mut results: HashSet<Result> = HashSet::new();
+        let compilation_units = core.get_compilation_units();
+
+        for compilation_unit in compilation_units {
+            for f in compilation_unit.functions_user_defined() {
+                let messages_pc: Vec<usize> = messages_to_l1(compilation_unit, f)
+                    .iter()
+                    .map(|message_to_l1| message_to_l1.pc)
+                    .collect();
+                if messages_pc.is_empty() {
+                    continue;
+                }
+
+                // The storage writes done directly or by a function called after the message is sent
+                let mut written_after = BTreeSet::new();
+                for (i, stmt) in f.get_statements().iter().enumerate() {
+                    let pc = f.entry_point() + i;
+                    if !messages_pc
+                        .iter()
+                        .any(|message_pc| f.instruction_dominates(*message_pc, pc))
+                    {
+                        continue;
+                    }
+                    if let GenStatement::Invocation(invoc) = stmt {
+                        if let CoreConcreteLibfunc::FunctionCall(f_called) = compilation_unit
+                            .registry()
+                            .get_libfunc(&invoc.libfunc_id)
+                            .expect("Library function not found in the registry")
+                        {
+                            written_after.extend(storage_writes_by(
+                                compilation_unit,
+                                f_called.function.id.debug_name.as_ref().unwrap(),
+                            ));
+                        }
+                    }
+                }
+                if written_after.is_empty() {
+                    continue;
+                }
+
+                let message = format!(
+                    "The function {} writes {} after sending a message to L1",
+                    f.name(),
+                    written_after
+                        .into_iter()
+                        .collect::<Vec<String>>()
+                        .join(", ")
+                );
+                results.insert(Result {
+                    name: self.name().to_string(),
+                    impact: self.impact(),
+                    confidence: self.confidence(),
+                    message,
+                });
+            }
+        }
+
+        results
+    }
+}
+
+/// Return the storage write functions called when calling the function
+fn storage_writes_by(compilation_unit: &CompilationUnit, function_name: &str) -> Vec<String> {
+    let function = match compilation_unit.function_by_name(function_name) {
+        Some(function) => function,
+        None => return Vec::new(),
+    };
+
+    // The function called is the write function of the storage variable
+    if *function.ty() == Type::Storage {
+        return if function_name.ends_with("write") {
+            vec![function_name.to_string()]
+        } else {
+            Vec::new()
+        };
+    }
+    if !matches!(function.ty(), Type::Private | Type::Loop) {
+        return Vec::new();
+    }
+
+    compilation_unit
+        .callgraph()
+        .reachable_from(function_name)
+        .iter()
+        .filter_map(|name| compilation_unit.function_by_name(name))
+        .flat_map(|f| f.storage_vars_written())
+        .filter_map(|stmt| match stmt {
+            GenStatement::Invocation(invoc) => match compilation_unit
+                .registry()
+                .get_libfunc(&invoc.libfunc_id)
+                .expect("Library function not found in the registry")
+            {
+                CoreConcreteLibfunc::FunctionCall(f_called) => f_called
+                    .function
+                    .id
+                    .debug_name
+                    .as_ref()
+                    .map(|name| name.to_string()),
+                _ => None,
+            },
+            GenStatement::Return(_) => None,
+        })
+        .collect()
+}
@@ -0,0 +1,135 @@
+use std::collections::{BTreeMap, BTreeSet, HashSet};
+
+use super::detector::{Confidence, Detector, Impact, Result};
+use crate::core::compilation_unit::CompilationUnit;
+use crate::core::core_unit::CoreUnit;
+use crate::core::function::Type;
+use cairo_lang_sierra::extensions::core::CoreConcreteLibfunc;
+use cairo_lang_sierra::extensions::starknet::StarkNetConcreteLibfunc;
+use cairo_lang_sierra::program::GenStatement;
+use cairo_lang_starknet::contract::starknet_keccak;
+use num_bigint::BigUint;
+
+const MEMBER_MODULE_PREFIX: &str = "__member_module_";
+
+#[derive(Default)]
+pub struct StorageCollision {}
+
+impl Detector for StorageCollision {
+    fn name(&self) -> &str {
+        "storage-collision"
+    }
+
+    fn description(&self) -> &str {
+        "Detect storage variables of the contract and its components with the same base address"
+    }
+
+    fn confidence(&self) -> Confidence {
+        Confidence::Medium
+    }
+
+    fn impact(&self) -> Impact {
+        Impact::High
+    }
+
+    fn run(&self, core: &CoreUnit) -> HashSet<Result> {
+        let mut results: HashSet<Result> = HashSet::new();
+        let compilation_units = core.get_compilation_units();
+
+        for compilation_unit in compilation_units {
+            for (address, storage_vars) in storage_base_addresses(compilation_unit) {
+                if storage_vars.len() < 2 {
+                    continue;
+                }
+
+                let message = format!(
+                    "The storage variables {} have the same base address {:#x}",
+                    storage_vars.into_iter().collect::<Vec<String>>().join(", "),
+                    address
+                );
+                results.insert(Result {
+                    name: self.name().to_string(),
+                    impact: self.impact(),
+                    confidence: self.confidence(),
+                    message,
+                });
+            }
+        }
+
+        results
+    }
+}
+
+/// Return the storage variables used in the compilation unit grouped by base address
+/// The base address is the starknet_keccak of the member name, the components storage is flattened
+/// in the contract storage so a member of a component and a member of the contract with the same name collide
+fn storage_base_addresses(
+    compilation_unit: &CompilationUnit,
+) -> BTreeMap<BigUint, BTreeSet<String>> {
+    let mut addresses: BTreeMap<BigUint, BTreeSet<String>> = BTreeMap::new();
+
+    for f in compilation_unit.functions() {
+        let name = f.name();
+        let mut storage_vars = member_modules(&name);
+        // Before cairo 2.2.0 the storage variable module is named as the member
+        if *f.ty() == Type::Storage {
+            if let Some((module, _)) = name.split_once("::InternalContractStateImpl::") {
+                storage_vars.push(module.to_string());
+            }
+        }
+
+        for storage_var in storage_vars {
+            let member = storage_var
+                .rsplit_once("::")
+                .map_or(storage_var.as_str(), |(_, member)| member);
+            let member = member.strip_prefix(MEMBER_MODULE_PREFIX).unwrap_or(member);
+            addresses
+                .entry(starknet_keccak(member.as_bytes()))
+                .or_default()
+                .insert(storage_var.clone());
+
+            // The address used by the read and write functions
+            if *f.ty() != Type::Storage {
+                continue;
+            }
+            for stmt in f.get_statements() {
+                if let GenStatement::Invocation(invoc) = stmt {
+                    if let CoreConcreteLibfunc::StarkNet(
+                        StarkNetConcreteLibfunc::StorageBaseAddressConst(base_address),
+                    ) = compilation_unit
+                        .registry()
+                        .get_libfunc(&invoc.libfunc_id)
+                        .expect("Library function not found in the registry")
+                    {
+                        if let Some(address) = base_address.c.to_biguint() {
+                            addresses
+                                .entry(address)
+                                .or_default()
+                                .insert(storage_var.clone());
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    addresses
+}
+
+/// Return the storage variable modules in the function name
+/// e.g. the member modules of the components appear in the generic arguments of the core storage functions
+fn member_modules(function_name: &str) -> Vec<String> {
+    let mut modules = Vec::new();
+
+    for (index, _) in function_name.match_indices(MEMBER_MODULE_PREFIX) {
+        let start = function_name[..index]
+            .rfind(['<', ' ', ',', '('])
+            .map_or(0, |delimiter| delimiter + 1);
+        let end = function_name[index..]
+            .find("::")
+            .map_or(function_name.len(), |end| index + end);
+        modules.push(function_name[start..end].to_string());
+    }
+
+    modules
+}
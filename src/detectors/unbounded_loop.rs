@@ -0,0 +1,209 @@
+use std::collections::{BTreeSet, HashSet};
+
+use super::detector::{Confidence, Detector, Impact, Result};
+use crate::analysis::caller_check::has_caller_check;
+use crate::analysis::taint::WrapperVariable;
+use crate::core::compilation_unit::CompilationUnit;
+use crate::core::core_unit::CoreUnit;
+use crate::core::function::{Function, Type};
+use crate::utils::{called_function_name, is_panic_result_match, storage_var_name, BUILTINS};
+use cairo_lang_sierra::program::{GenStatement, Statement as SierraStatement};
+use fxhash::FxHashSet;
+
+#[derive(Default)]
+pub struct UnboundedLoop {}
+
+impl Detector for UnboundedLoop {
+    fn name(&self) -> &str {
+        "unbounded-loop"
+    }
+
+    fn description(&self) -> &str {
+        "Detect loops iterating a number of times controlled by the user"
+    }
+
+    fn confidence(&self) -> Confidence {
+        Confidence::Low
+    }
+
+    fn impact(&self) -> Impact {
+        Impact::Medium
+    }
+
+    fn run(&self, core: &CoreUnit) -> HashSet<Result> {
+        let mut results: HashSet<Result> = HashSet::new();
+        let compilation_units = core.get_compilation_units();
+
+        for compilation_unit in compilation_units {
+            let growable_storage_vars = growable_storage_vars(compilation_unit);
+
+            for f in compilation_unit.functions_user_defined() {
+                let taint = match compilation_unit.get_taint(&f.name()) {
+                    Some(taint) => taint,
+                    None => continue,
+                };
+
+                // Values read from a storage variable anyone can make grow
+                let unbounded_storage_reads: FxHashSet<WrapperVariable> = f
+                    .storage_vars_read()
+                    .filter_map(|stmt| match stmt {
+                        SierraStatement::Invocation(invoc)
+                            if called_function_name(compilation_unit.registry(), stmt)
+                                .is_some_and(|name| {
+                                    growable_storage_vars.contains(storage_var_name(&name))
+                                }) =>
+                        {
+                            invoc.branches[0].results.last()
+                        }
+                        _ => None,
+                    })
+                    .map(|var| WrapperVariable::new(f.name(), var.id))
+                    .collect();
+
+                for stmt in f.loop_functions_calls() {
+                    let loop_function =
+                        match called_function_name(compilation_unit.registry(), stmt)
+                            .and_then(|name| compilation_unit.function_by_name(&name))
+                        {
+                            // The recursive call of the loop is not a new loop
+                            Some(loop_function) if loop_function.name() != f.name() => {
+                                loop_function
+                            }
+                            _ => continue,
+                        };
+                    let invoc = match stmt {
+                        SierraStatement::Invocation(invoc) => invoc,
+                        SierraStatement::Return(_) => continue,
+                    };
+
+                    let is_unbounded = controlling_params(compilation_unit, loop_function)
+                        .into_iter()
+                        .filter_map(|index| invoc.args.get(index))
+                        .any(|arg| {
+                            compilation_unit.is_tainted(f.name(), arg.clone())
+                                || taint.taints_any_sources(
+                                    &unbounded_storage_reads,
+                                    &WrapperVariable::new(f.name(), arg.id),
+                                )
+                        });
+                    if !is_unbounded {
+                        continue;
+                    }
+
+                    let message = format!(
+                        "The loop {} iterates a number of times controlled by the user, it's reachable from {}",
+                        loop_function.name(),
+                        entry_points_reaching(compilation_unit, f)
+                            .into_iter()
+                            .collect::<Vec<String>>()
+                            .join(", ")
+                    );
+                    results.insert(Result {
+                        name: self.name().to_string(),
+                        impact: self.impact(),
+                        confidence: self.confidence(),
+                        message,
+                    });
+                }
+            }
+        }
+
+        results
+    }
+}
+
+/// Return the index of the parameters of the loop function deciding when the loop ends
+/// i.e. the parameters flowing in a branch condition, the builtins are excluded
+fn controlling_params(compilation_unit: &CompilationUnit, loop_function: &Function) -> Vec<usize> {
+    let taint = match compilation_unit.get_taint(&loop_function.name()) {
+        Some(taint) => taint,
+        None => return Vec::new(),
+    };
+
+    let mut conditions = FxHashSet::default();
+    for stmt in loop_function.get_statements() {
+        if let GenStatement::Invocation(invoc) = stmt {
+            if invoc.branches.len() < 2 {
+                continue;
+            }
+            // Matching a PanicResult only checks if a function called panicked
+            if is_panic_result_match(
+                compilation_unit
+                    .registry()
+                    .get_libfunc(&invoc.libfunc_id)
+                    .expect("Library function not found in the registry"),
+            ) {
+                continue;
+            }
+            conditions.extend(
+                invoc
+                    .args
+                    .iter()
+                    .map(|arg| WrapperVariable::new(loop_function.name(), arg.id)),
+            );
+        }
+    }
+
+    loop_function
+        .params_all()
+        .enumerate()
+        .filter(|(_, param)| {
+            param
+                .ty
+                .debug_name
+                .as_ref()
+                .is_some_and(|ty| !BUILTINS.contains(&ty.as_str()))
+                && taint.taints_any_sinks(
+                    &WrapperVariable::new(loop_function.name(), param.id.id),
+                    &conditions,
+                )
+        })
+        .map(|(index, _)| index)
+        .collect()
+}
+
+/// Return the storage variables written in a function reachable from an entry point without caller check
+fn growable_storage_vars(compilation_unit: &CompilationUnit) -> HashSet<String> {
+    let callgraph = compilation_unit.callgraph();
+    let mut storage_vars = HashSet::new();
+
+    for entry_point in compilation_unit
+        .functions_user_defined()
+        .filter(|f| matches!(f.ty(), Type::External | Type::L1Handler))
+        .filter(|f| !has_caller_check(compilation_unit, f))
+    {
+        for function in callgraph
+            .reachable_from(&entry_point.name())
+            .iter()
+            .filter_map(|name| compilation_unit.function_by_name(name))
+        {
+            storage_vars.extend(
+                function
+                    .storage_vars_written()
+                    .filter_map(|stmt| called_function_name(compilation_unit.registry(), stmt))
+                    .map(|name| storage_var_name(&name).to_string()),
+            );
+        }
+    }
+
+    storage_vars
+}
+
+/// Return the external functions and L1 handlers from which the function is reachable
+fn entry_points_reaching(
+    compilation_unit: &CompilationUnit,
+    function: &Function,
+) -> BTreeSet<String> {
+    compilation_unit
+        .functions_user_defined()
+        .filter(|f| matches!(f.ty(), Type::External | Type::L1Handler | Type::View))
+        .filter(|f| {
+            f.name() == function.name()
+                || compilation_unit
+                    .callgraph()
+                    .reachable_from(&f.name())
+                    .contains(&function.name())
+        })
+        .map(|f| f.name())
+        .collect()
+}
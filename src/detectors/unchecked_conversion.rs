@@ -0,0 +1,118 @@
+use std::collections::HashSet;
+
+use super::detector::{Confidence, Detector, Impact, Result};
+use crate::analysis::panics::{always_panics_from, is_unwrapped};
+use crate::core::core_unit::CoreUnit;
+use crate::utils::{
+    branch_target, filter_builtins_from_arguments, is_narrowing_function, is_narrowing_libfunc,
+};
+use cairo_lang_sierra::extensions::core::CoreConcreteLibfunc;
+use cairo_lang_sierra::extensions::ConcreteLibfunc;
+use cairo_lang_sierra::program::GenStatement;
+
+#[derive(Default)]
+pub struct UncheckedConversion {}
+
+impl Detector for UncheckedConversion {
+    fn name(&self) -> &str {
+        "unchecked-conversion"
+    }
+
+    fn description(&self) -> &str {
+        "Detect narrowing conversions of user controlled values that panic or truncate"
+    }
+
+    fn confidence(&self) -> Confidence {
+        Confidence::Low
+    }
+
+    fn impact(&self) -> Impact {
+        Impact::Medium
+    }
+
+    fn run(&self, core: &CoreUnit) -> HashSet<Result> {
+        let mut results: HashSet<Result> = HashSet::new();
+        let compilation_units = core.get_compilation_units();
+
+        for compilation_unit in compilation_units {
+            for f in compilation_unit.functions_user_defined() {
+                for (i, stmt) in f.get_statements().iter().enumerate() {
+                    let pc = f.entry_point() + i;
+                    if let GenStatement::Invocation(invoc) = stmt {
+                        let libfunc = compilation_unit
+                            .registry()
+                            .get_libfunc(&invoc.libfunc_id)
+                            .expect("Library function not found in the registry");
+
+                        let message = match libfunc {
+                            // The libfunc is called directly, the second branch is taken when the value doesn't fit
+                            _ if is_narrowing_libfunc(libfunc) => {
+                                let value = filter_builtins_from_arguments(
+                                    libfunc.param_signatures(),
+                                    invoc.args.clone(),
+                                );
+                                match (value.first(), invoc.branches.get(1)) {
+                                    (Some(value), Some(failure))
+                                        if compilation_unit.is_tainted(f.name(), value.clone())
+                                            && always_panics_from(
+                                                f,
+                                                compilation_unit.registry(),
+                                                branch_target(failure, pc),
+                                            ) =>
+                                    {
+                                        Some(self.panic_message(
+                                            invoc.libfunc_id.debug_name.as_ref().unwrap(),
+                                            &f.name(),
+                                        ))
+                                    }
+                                    _ => None,
+                                }
+                            }
+                            // The libfunc is wrapped in a core function returning an Option
+                            CoreConcreteLibfunc::FunctionCall(f_called) => {
+                                let name = f_called.function.id.debug_name.as_ref().unwrap();
+                                if !is_narrowing_function(name) {
+                                    continue;
+                                }
+                                let value = filter_builtins_from_arguments(
+                                    &f_called.signature.param_signatures,
+                                    invoc.args.clone(),
+                                );
+                                match (value.first(), invoc.branches[0].results.last()) {
+                                    (Some(value), Some(option))
+                                        if compilation_unit.is_tainted(f.name(), value.clone())
+                                            && is_unwrapped(compilation_unit, f, option, pc) =>
+                                    {
+                                        Some(self.panic_message(name, &f.name()))
+                                    }
+                                    _ => None,
+                                }
+                            }
+                            _ => None,
+                        };
+
+                        if let Some(message) = message {
+                            results.insert(Result {
+                                name: self.name().to_string(),
+                                impact: self.impact(),
+                                confidence: self.confidence(),
+                                message,
+                            });
+                        }
+                    }
+                }
+            }
+        }
+
+        results
+    }
+}
+
+impl UncheckedConversion {
+    fn panic_message(&self, conversion: &str, function_name: &str) -> String {
+        format!(
+            "The conversion {} in {} panics when the user controlled value doesn't fit the target type",
+            conversion, function_name
+        )
+    }
+}
@@ -0,0 +1,346 @@
+use std::collections::HashSet;
+
+use super::detector::{Confidence, Detector, Impact, Result};
+use crate::analysis::def_use::{Definition, Value};
+use crate::analysis::interval::IntervalAnalysis;
+use crate::core::compilation_unit::CompilationUnit;
+use crate::core::core_unit::CoreUnit;
+use crate::core::function::Function;
+use crate::utils::{
+    branch_target, called_function_name, filter_builtins_from_arguments,
+    filter_builtins_from_signature,
+};
+use cairo_lang_sierra::extensions::core::CoreConcreteLibfunc;
+use cairo_lang_sierra::extensions::enm::EnumConcreteLibfunc;
+use cairo_lang_sierra::extensions::felt252::{
+    Felt252BinaryOperationConcrete, Felt252BinaryOperator, Felt252Concrete,
+};
+use cairo_lang_sierra::extensions::int::unsigned::UintConcrete;
+use cairo_lang_sierra::extensions::int::unsigned128::Uint128Concrete;
+use cairo_lang_sierra::extensions::int::unsigned256::Uint256Concrete;
+use cairo_lang_sierra::extensions::ConcreteLibfunc;
+use cairo_lang_sierra::ids::VarId;
+use cairo_lang_sierra::program::GenStatement;
+use num_bigint::BigInt;
+use num_traits::Zero;
+
+#[derive(Default)]
+pub struct UncheckedDivision {}
+
+impl Detector for UncheckedDivision {
+    fn name(&self) -> &str {
+        "unchecked-division"
+    }
+
+    fn description(&self) -> &str {
+        "Detect divisions by a user controlled value without a zero check and felt252 divisions"
+    }
+
+    fn confidence(&self) -> Confidence {
+        Confidence::Medium
+    }
+
+    fn impact(&self) -> Impact {
+        Impact::Medium
+    }
+
+    fn run(&self, core: &CoreUnit) -> HashSet<Result> {
+        let mut results: HashSet<Result> = HashSet::new();
+        let compilation_units = core.get_compilation_units();
+
+        for compilation_unit in compilation_units {
+            for f in compilation_unit.functions_user_defined() {
+                for (i, stmt) in f.get_statements().iter().enumerate() {
+                    let pc = f.entry_point() + i;
+                    let invoc = match stmt {
+                        GenStatement::Invocation(invoc) => invoc,
+                        GenStatement::Return(_) => continue,
+                    };
+                    let libfunc = compilation_unit
+                        .registry()
+                        .get_libfunc(&invoc.libfunc_id)
+                        .expect("Library function not found in the registry");
+
+                    let (is_felt252, param_signatures) = match libfunc {
+                        CoreConcreteLibfunc::Felt252(Felt252Concrete::BinaryOperation(
+                            Felt252BinaryOperationConcrete::WithVar(op),
+                        )) if op.operator == Felt252BinaryOperator::Div => {
+                            (true, libfunc.param_signatures())
+                        }
+                        // The divisor is a constant, it's not zero
+                        CoreConcreteLibfunc::Felt252(Felt252Concrete::BinaryOperation(
+                            Felt252BinaryOperationConcrete::WithConst(op),
+                        )) if op.operator == Felt252BinaryOperator::Div => {
+                            self.add_result(&mut results, self.felt252_div_message(&f.name()));
+                            continue;
+                        }
+                        CoreConcreteLibfunc::Uint8(UintConcrete::Divmod(_))
+                        | CoreConcreteLibfunc::Uint16(UintConcrete::Divmod(_))
+                        | CoreConcreteLibfunc::Uint32(UintConcrete::Divmod(_))
+                        | CoreConcreteLibfunc::Uint64(UintConcrete::Divmod(_))
+                        | CoreConcreteLibfunc::Uint128(Uint128Concrete::Divmod(_))
+                        | CoreConcreteLibfunc::Uint256(Uint256Concrete::Divmod(_)) => {
+                            (false, libfunc.param_signatures())
+                        }
+                        // The division is done in a core function
+                        CoreConcreteLibfunc::FunctionCall(f_called) => {
+                            let name = f_called.function.id.debug_name.as_ref().unwrap();
+                            if !is_integer_division_function(name) {
+                                continue;
+                            }
+                            (false, f_called.signature.param_signatures.as_slice())
+                        }
+                        _ => continue,
+                    };
+
+                    if is_felt252 {
+                        self.add_result(&mut results, self.felt252_div_message(&f.name()));
+                    }
+
+                    // The second argument after the builtins is the divisor
+                    let divisor =
+                        match filter_builtins_from_arguments(param_signatures, invoc.args.clone())
+                            .get(1)
+                        {
+                            Some(divisor) => divisor.clone(),
+                            None => continue,
+                        };
+                    // A NonZero divisor can only come from a zero check
+                    let is_non_zero = filter_builtins_from_signature(param_signatures)
+                        .get(1)
+                        .and_then(|param| param.ty.debug_name.as_ref())
+                        .is_some_and(|ty| ty.starts_with("NonZero<"));
+
+                    if compilation_unit.is_tainted(f.name(), divisor.clone())
+                        && !is_non_zero
+                        && !is_checked(compilation_unit, f, &divisor, pc)
+                    {
+                        self.add_result(
+                            &mut results,
+                            format!(
+                                "The function {} divides by a user controlled value without checking it is not zero",
+                                f.name()
+                            ),
+                        );
+                    }
+                }
+            }
+        }
+
+        results
+    }
+}
+
+impl UncheckedDivision {
+    fn felt252_div_message(&self, function_name: &str) -> String {
+        format!(
+            "The function {} uses felt252_div which computes a field inversion instead of an integer division",
+            function_name
+        )
+    }
+
+    fn add_result(&self, results: &mut HashSet<Result>, message: String) {
+        results.insert(Result {
+            name: self.name().to_string(),
+            impact: self.impact(),
+            confidence: self.confidence(),
+            message,
+        });
+    }
+}
+
+fn is_integer_division_function(name: &str) -> bool {
+    name.starts_with("core::integer::")
+        && (name.ends_with("Div::div")
+            || name.ends_with("Rem::rem")
+            || name.ends_with("DivRem::div_rem")
+            || name.ends_with("DivEq::div_eq")
+            || name.ends_with("RemEq::rem_eq"))
+}
+
+/// Return true if the divisor can't be zero or if its value is checked before the division at pc
+/// i.e. the division is only executed in the non-zero branch of a zero check of the divisor
+fn is_checked(
+    compilation_unit: &CompilationUnit,
+    function: &Function,
+    divisor: &VarId,
+    pc: usize,
+) -> bool {
+    let registry = compilation_unit.registry();
+
+    // The value is never zero
+    if IntervalAnalysis::state_before(function, pc, registry)
+        .interval(divisor)
+        .is_some_and(|interval| !interval.contains(&BigInt::zero()))
+    {
+        return true;
+    }
+
+    let divisor_values = origins(compilation_unit, function, divisor, pc);
+    function
+        .get_statements()
+        .iter()
+        .enumerate()
+        .any(|(i, stmt)| {
+            let check_pc = function.entry_point() + i;
+            let invoc = match stmt {
+                GenStatement::Invocation(invoc) => invoc,
+                GenStatement::Return(_) => return false,
+            };
+            match zero_check(compilation_unit, function, check_pc) {
+                Some((checked, non_zero_branch)) => {
+                    function.instruction_dominates(
+                        branch_target(&invoc.branches[non_zero_branch], check_pc),
+                        pc,
+                    ) && origins(compilation_unit, function, &checked, check_pc)
+                        .iter()
+                        .any(|value| divisor_values.contains(value))
+                }
+                None => false,
+            }
+        })
+}
+
+/// Return the value checked by the statement at pc and the index of the branch taken when it's not zero
+/// if the statement is a zero check i.e. felt252_is_zero, u*_is_zero or an equality with 0
+fn zero_check(
+    compilation_unit: &CompilationUnit,
+    function: &Function,
+    pc: usize,
+) -> Option<(VarId, usize)> {
+    let invoc = match &function.get_statements()[pc - function.entry_point()] {
+        GenStatement::Invocation(invoc) => invoc,
+        GenStatement::Return(_) => return None,
+    };
+    let registry = compilation_unit.registry();
+    let is_zero_constant =
+        |var: &VarId, at: usize| is_zero_constant(compilation_unit, function, var, at);
+
+    match registry
+        .get_libfunc(&invoc.libfunc_id)
+        .expect("Library function not found in the registry")
+    {
+        // The first branch is taken when the value is zero
+        CoreConcreteLibfunc::Felt252(Felt252Concrete::IsZero(_))
+        | CoreConcreteLibfunc::Uint8(UintConcrete::IsZero(_))
+        | CoreConcreteLibfunc::Uint16(UintConcrete::IsZero(_))
+        | CoreConcreteLibfunc::Uint32(UintConcrete::IsZero(_))
+        | CoreConcreteLibfunc::Uint64(UintConcrete::IsZero(_))
+        | CoreConcreteLibfunc::Uint128(Uint128Concrete::IsZero(_))
+        | CoreConcreteLibfunc::Uint256(Uint256Concrete::IsZero(_)) => {
+            invoc.args.first().map(|checked| (checked.clone(), 1))
+        }
+        // The first branch is taken when the values are not equal
+        CoreConcreteLibfunc::Uint8(UintConcrete::Equal(_))
+        | CoreConcreteLibfunc::Uint16(UintConcrete::Equal(_))
+        | CoreConcreteLibfunc::Uint32(UintConcrete::Equal(_))
+        | CoreConcreteLibfunc::Uint64(UintConcrete::Equal(_))
+        | CoreConcreteLibfunc::Uint128(Uint128Concrete::Equal(_)) => {
+            compared_with_zero(&invoc.args, pc, is_zero_constant).map(|checked| (checked, 0))
+        }
+        // The bool returned by PartialEq::eq or PartialEq::ne, the first branch is false
+        CoreConcreteLibfunc::Enum(EnumConcreteLibfunc::Match(enum_match))
+            if enum_match.signature.param_signatures[0]
+                .ty
+                .debug_name
+                .as_deref()
+                == Some("core::bool") =>
+        {
+            let definitions = function
+                .def_use(registry)
+                .value_definitions(&invoc.args[0], pc);
+            let definition = match definitions.as_slice() {
+                [value] => value.definition,
+                _ => return None,
+            };
+            let def_pc = match definition {
+                Definition::Statement(def_pc) => def_pc,
+                Definition::Parameter => return None,
+            };
+            let comparison = match &function.get_statements()[def_pc - function.entry_point()] {
+                GenStatement::Invocation(comparison) => comparison,
+                GenStatement::Return(_) => return None,
+            };
+            let non_zero_branch = match called_function_name(
+                registry,
+                &function.get_statements()[def_pc - function.entry_point()],
+            ) {
+                Some(name) if name.starts_with("core::") && name.contains("PartialEq") => {
+                    if name.ends_with("::eq") {
+                        0
+                    } else if name.ends_with("::ne") {
+                        1
+                    } else {
+                        return None;
+                    }
+                }
+                _ => return None,
+            };
+            compared_with_zero(&comparison.args, def_pc, is_zero_constant)
+                .map(|checked| (checked, non_zero_branch))
+        }
+        _ => None,
+    }
+}
+
+/// Return the value compared with 0 if one of the two arguments is the constant 0
+fn compared_with_zero(
+    args: &[VarId],
+    pc: usize,
+    is_zero_constant: impl Fn(&VarId, usize) -> bool,
+) -> Option<VarId> {
+    match args {
+        [a, b] if is_zero_constant(b, pc) => Some(a.clone()),
+        [a, b] if is_zero_constant(a, pc) => Some(b.clone()),
+        _ => None,
+    }
+}
+
+/// Return true if the variable used at pc always holds 0
+/// e.g. a constant or a value bounded to 0 by the previous checks
+fn is_zero_constant(
+    compilation_unit: &CompilationUnit,
+    function: &Function,
+    var: &VarId,
+    pc: usize,
+) -> bool {
+    IntervalAnalysis::state_before(function, pc, compilation_unit.registry())
+        .constant(var)
+        .is_some_and(|constant| constant.is_zero())
+}
+
+/// Return where the values held by the variable used at pc were originally defined
+/// A snapshot holds the same value as the variable it's taken from
+fn origins(
+    compilation_unit: &CompilationUnit,
+    function: &Function,
+    var: &VarId,
+    pc: usize,
+) -> Vec<Value> {
+    let registry = compilation_unit.registry();
+    let def_use = function.def_use(registry);
+    let mut origins = Vec::new();
+    let mut worklist = def_use.value_definitions(var, pc);
+
+    while let Some(value) = worklist.pop() {
+        if origins.contains(&value) {
+            continue;
+        }
+        if let Definition::Statement(def_pc) = value.definition {
+            if let GenStatement::Invocation(invoc) =
+                &function.get_statements()[def_pc - function.entry_point()]
+            {
+                if let CoreConcreteLibfunc::SnapshotTake(_) = registry
+                    .get_libfunc(&invoc.libfunc_id)
+                    .expect("Library function not found in the registry")
+                {
+                    worklist.extend(def_use.value_definitions(&invoc.args[0], def_pc));
+                    continue;
+                }
+            }
+        }
+        origins.push(value);
+    }
+
+    origins
+}
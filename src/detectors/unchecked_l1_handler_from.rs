@@ -5,7 +5,7 @@ use crate::core::core_unit::CoreUnit;
 use crate::core::function::{Function, Type};
 use cairo_lang_sierra::extensions::{core::CoreConcreteLibfunc, felt252::Felt252Concrete};
 use cairo_lang_sierra::ids::VarId;
-use cairo_lang_sierra::program::{GenStatement, Statement as SierraStatement};
+use cairo_lang_sierra::program::Statement as SierraStatement;
 use fxhash::FxHashSet;
 use std::collections::HashSet;
 
@@ -45,16 +45,15 @@ impl Detector for UncheckedL1HandlerFrom {
                 let mut sources = FxHashSet::default();
                 sources.insert(WrapperVariable::new(f.name(), from_address.id));
 
-                // Used to avoid infinite recursion in case of recursive private function calls
-                let mut checked_private_functions = HashSet::new();
-
-                // Check if any call to felt252_is_zero uses from_address argument
-                let from_checked = self.is_from_checked_in_function(
-                    &sources,
-                    compilation_unit,
-                    f,
-                    &mut checked_private_functions,
-                );
+                // Check if any call to felt252_is_zero in the handler or the functions it calls uses from_address argument
+                let from_checked = compilation_unit
+                    .callgraph()
+                    .reachable_from(&f.name())
+                    .iter()
+                    .filter_map(|name| compilation_unit.function_by_name(name))
+                    .any(|function| {
+                        self.is_from_checked_in_function(&sources, compilation_unit, function)
+                    });
 
                 if !from_checked {
                     let message = format!(
@@ -81,9 +80,9 @@ impl UncheckedL1HandlerFrom {
         from_tainted_args: &FxHashSet<WrapperVariable>,
         compilation_unit: &CompilationUnit,
         function: &Function,
-        checked_private_functions: &mut HashSet<String>,
     ) -> bool {
-        let from_checked = function
+        // The taints are propagated to the private functions so the from address is a source for them too
+        function
             .get_statements()
             .iter()
             .filter_map(|stmt| match stmt {
@@ -106,56 +105,7 @@ impl UncheckedL1HandlerFrom {
                         ),
                     _ => false,
                 }
-            });
-
-        let from_checked_in_private_functions = from_checked
-            || function.private_functions_calls().any(|s| {
-                if let GenStatement::Invocation(invoc) = s {
-                    let lib_func = compilation_unit
-                        .registry()
-                        .get_libfunc(&invoc.libfunc_id)
-                        .expect("Library function not found in the registry");
-
-                    if let CoreConcreteLibfunc::FunctionCall(f_called) = lib_func {
-                        let private_function = compilation_unit
-                            .function_by_name(f_called.function.id.debug_name.as_ref().unwrap())
-                            .unwrap();
-                        if checked_private_functions.contains(&private_function.name()) {
-                            return false;
-                        }
-
-                        let taint = compilation_unit.get_taint(&function.name()).unwrap();
-
-                        let sinks: FxHashSet<WrapperVariable> = invoc
-                            .args
-                            .iter()
-                            .map(|v| WrapperVariable::new(function.name(), v.id))
-                            .collect();
-
-                        let from_tainted_args: FxHashSet<WrapperVariable> = from_tainted_args
-                            .iter()
-                            .flat_map(|source| taint.taints_any_sinks_variable(source, &sinks))
-                            .map(|sink| {
-                                WrapperVariable::new(
-                                    private_function.name(),
-                                    sink.variable() - invoc.args[0].id,
-                                )
-                            })
-                            .collect();
-
-                        checked_private_functions.insert(private_function.name());
-                        return self.is_from_checked_in_function(
-                            &from_tainted_args,
-                            compilation_unit,
-                            private_function,
-                            checked_private_functions,
-                        );
-                    }
-                }
-                false
-            });
-
-        from_checked_in_private_functions
+            })
     }
 
     fn is_felt252_is_zero_arg_tainted_by_from_address(
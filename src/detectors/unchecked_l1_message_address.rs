@@ -0,0 +1,82 @@
+use std::collections::HashSet;
+
+use super::detector::{Confidence, Detector, Impact, Result};
+use crate::analysis::l1_message::{is_validated_before, messages_to_l1};
+use crate::analysis::taint::WrapperVariable;
+use crate::core::core_unit::CoreUnit;
+use cairo_lang_sierra::program::GenStatement;
+use fxhash::FxHashSet;
+
+#[derive(Default)]
+pub struct UncheckedL1MessageAddress {}
+
+impl Detector for UncheckedL1MessageAddress {
+    fn name(&self) -> &str {
+        "unchecked-l1-message-address"
+    }
+
+    fn description(&self) -> &str {
+        "Detect messages sent to a user controlled L1 address without validation"
+    }
+
+    fn confidence(&self) -> Confidence {
+        Confidence::Medium
+    }
+
+    fn impact(&self) -> Impact {
+        Impact::High
+    }
+
+    fn run(&self, core: &CoreUnit) -> HashSet<Result> {
+        let mut results: HashSet<Result> = HashSet::new();
+        let compilation_units = core.get_compilation_units();
+
+        for compilation_unit in compilation_units {
+            for f in compilation_unit.functions_user_defined() {
+                let taint = match compilation_unit.get_taint(&f.name()) {
+                    Some(taint) => taint,
+                    None => continue,
+                };
+
+                // An address read from the storage is set by the contract
+                let storage_reads: FxHashSet<WrapperVariable> = f
+                    .storage_vars_read()
+                    .filter_map(|stmt| match stmt {
+                        GenStatement::Invocation(invoc) => invoc.branches[0].results.last(),
+                        GenStatement::Return(_) => None,
+                    })
+                    .map(|var| WrapperVariable::new(f.name(), var.id))
+                    .collect();
+
+                for message_to_l1 in messages_to_l1(compilation_unit, f) {
+                    if taint.taints_any_sources(
+                        &storage_reads,
+                        &WrapperVariable::new(f.name(), message_to_l1.to_address.id),
+                    ) || !compilation_unit.is_tainted(f.name(), message_to_l1.to_address.clone())
+                        || is_validated_before(
+                            compilation_unit,
+                            f,
+                            &message_to_l1.to_address,
+                            message_to_l1.pc,
+                        )
+                    {
+                        continue;
+                    }
+
+                    let message = format!(
+                        "The function {} sends a message to a user controlled L1 address without validating it",
+                        f.name()
+                    );
+                    results.insert(Result {
+                        name: self.name().to_string(),
+                        impact: self.impact(),
+                        confidence: self.confidence(),
+                        message,
+                    });
+                }
+            }
+        }
+
+        results
+    }
+}
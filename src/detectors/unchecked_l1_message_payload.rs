@@ -0,0 +1,83 @@
+use std::collections::HashSet;
+
+use super::detector::{Confidence, Detector, Impact, Result};
+use crate::analysis::l1_message::{is_bounded_as_l1_address_before, messages_to_l1};
+use crate::analysis::taint::WrapperVariable;
+use crate::core::core_unit::CoreUnit;
+use fxhash::FxHashSet;
+
+#[derive(Default)]
+pub struct UncheckedL1MessagePayload {}
+
+impl Detector for UncheckedL1MessagePayload {
+    fn name(&self) -> &str {
+        "unchecked-l1-message-payload"
+    }
+
+    fn description(&self) -> &str {
+        "Detect messages sent to L1 with a user controlled felt252 not validated as an L1 address"
+    }
+
+    fn confidence(&self) -> Confidence {
+        Confidence::Low
+    }
+
+    fn impact(&self) -> Impact {
+        Impact::Medium
+    }
+
+    fn run(&self, core: &CoreUnit) -> HashSet<Result> {
+        let mut results: HashSet<Result> = HashSet::new();
+        let compilation_units = core.get_compilation_units();
+
+        for compilation_unit in compilation_units {
+            for f in compilation_unit.functions_user_defined() {
+                let taint = match compilation_unit.get_taint(&f.name()) {
+                    Some(taint) => taint,
+                    None => continue,
+                };
+
+                for message_to_l1 in messages_to_l1(compilation_unit, f) {
+                    let payload = FxHashSet::from_iter([WrapperVariable::new(
+                        f.name(),
+                        message_to_l1.payload.id,
+                    )]);
+
+                    // A raw felt252 can be bigger than an L1 address (2^160) unlike an EthAddress
+                    // which is checked when deserialized, any other check doesn't bound the value
+                    let is_unchecked = f
+                        .params()
+                        .filter(|param| param.ty.debug_name.as_deref() == Some("felt252"))
+                        .filter(|param| compilation_unit.is_tainted(f.name(), param.id.clone()))
+                        .any(|param| {
+                            taint.taints_any_sinks(
+                                &WrapperVariable::new(f.name(), param.id.id),
+                                &payload,
+                            ) && !is_bounded_as_l1_address_before(
+                                compilation_unit,
+                                f,
+                                &param.id,
+                                message_to_l1.pc,
+                            )
+                        });
+                    if !is_unchecked {
+                        continue;
+                    }
+
+                    let message = format!(
+                        "The function {} sends a message to L1 with a user controlled felt252 in the payload which can be bigger than an L1 address",
+                        f.name()
+                    );
+                    results.insert(Result {
+                        name: self.name().to_string(),
+                        impact: self.impact(),
+                        confidence: self.confidence(),
+                        message,
+                    });
+                }
+            }
+        }
+
+        results
+    }
+}
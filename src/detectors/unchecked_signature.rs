@@ -0,0 +1,55 @@
+use std::collections::HashSet;
+
+use super::detector::{Confidence, Detector, Impact, Result};
+use crate::analysis::signature::{is_result_used, signature_verifications};
+use crate::core::core_unit::CoreUnit;
+
+#[derive(Default)]
+pub struct UncheckedSignature {}
+
+impl Detector for UncheckedSignature {
+    fn name(&self) -> &str {
+        "unchecked-signature"
+    }
+
+    fn description(&self) -> &str {
+        "Detect signature verifications where the result is not checked"
+    }
+
+    fn confidence(&self) -> Confidence {
+        Confidence::High
+    }
+
+    fn impact(&self) -> Impact {
+        Impact::High
+    }
+
+    fn run(&self, core: &CoreUnit) -> HashSet<Result> {
+        let mut results: HashSet<Result> = HashSet::new();
+        let compilation_units = core.get_compilation_units();
+
+        for compilation_unit in compilation_units {
+            for f in compilation_unit.functions_user_defined() {
+                for verification in signature_verifications(compilation_unit, f) {
+                    if is_result_used(compilation_unit, f, &verification) {
+                        continue;
+                    }
+
+                    let message = format!(
+                        "The result of the signature verification {} in {} is not checked",
+                        verification.name,
+                        f.name()
+                    );
+                    results.insert(Result {
+                        name: self.name().to_string(),
+                        impact: self.impact(),
+                        confidence: self.confidence(),
+                        message,
+                    });
+                }
+            }
+        }
+
+        results
+    }
+}
@@ -0,0 +1,148 @@
+use std::collections::HashSet;
+
+use super::detector::{Confidence, Detector, Impact, Result};
+use crate::analysis::def_use::{results_at, Definition, Value};
+use crate::core::compilation_unit::CompilationUnit;
+use crate::core::core_unit::CoreUnit;
+use crate::core::function::{Function, Type};
+use crate::utils::is_panic_result_match;
+use cairo_lang_sierra::extensions::core::CoreConcreteLibfunc;
+use cairo_lang_sierra::ids::VarId;
+use cairo_lang_sierra::program::GenStatement;
+use fxhash::FxHashSet;
+
+/// ERC20 functions returning a bool to check
+const ERC20_FUNCTIONS: [&str; 4] = ["transfer", "transfer_from", "transferFrom", "approve"];
+
+#[derive(Default)]
+pub struct UncheckedTransfer {}
+
+impl Detector for UncheckedTransfer {
+    fn name(&self) -> &str {
+        "unchecked-transfer"
+    }
+
+    fn description(&self) -> &str {
+        "Detect ERC20 transfer, transfer_from and approve calls where the returned bool is not checked"
+    }
+
+    fn confidence(&self) -> Confidence {
+        Confidence::Medium
+    }
+
+    fn impact(&self) -> Impact {
+        Impact::High
+    }
+
+    fn run(&self, core: &CoreUnit) -> HashSet<Result> {
+        let mut results: HashSet<Result> = HashSet::new();
+        let compilation_units = core.get_compilation_units();
+
+        for compilation_unit in compilation_units {
+            for f in compilation_unit.functions_user_defined() {
+                for (i, stmt) in f.get_statements().iter().enumerate() {
+                    let pc = f.entry_point() + i;
+                    let invoc = match stmt {
+                        GenStatement::Invocation(invoc) => invoc,
+                        GenStatement::Return(_) => continue,
+                    };
+                    let f_called = match compilation_unit
+                        .registry()
+                        .get_libfunc(&invoc.libfunc_id)
+                        .expect("Library function not found in the registry")
+                    {
+                        CoreConcreteLibfunc::FunctionCall(f_called) => f_called,
+                        _ => continue,
+                    };
+                    let name = f_called.function.id.debug_name.as_ref().unwrap();
+                    let is_erc20_call = compilation_unit
+                        .function_by_name(name)
+                        .is_some_and(|function| *function.ty() == Type::AbiCallContract)
+                        && name
+                            .rsplit_once("::")
+                            .is_some_and(|(_, method)| ERC20_FUNCTIONS.contains(&method))
+                        // The function called returns a PanicResult wrapping the bool
+                        && f_called.signature.branch_signatures[0]
+                            .vars
+                            .last()
+                            .and_then(|var| var.ty.debug_name.as_ref())
+                            .is_some_and(|ty| ty.ends_with("(core::bool,)>"));
+                    if !is_erc20_call {
+                        continue;
+                    }
+
+                    let is_checked = invoc.branches[0]
+                        .results
+                        .last()
+                        .is_some_and(|result| is_bool_checked(compilation_unit, f, result, pc));
+                    if is_checked {
+                        continue;
+                    }
+
+                    let message = format!(
+                        "The bool returned by {} is not checked in {}",
+                        name,
+                        f.name()
+                    );
+                    results.insert(Result {
+                        name: self.name().to_string(),
+                        impact: self.impact(),
+                        confidence: self.confidence(),
+                        message,
+                    });
+                }
+            }
+        }
+
+        results
+    }
+}
+
+/// Return true if the bool held by the PanicResult reaches a branch
+/// a bool returned or passed to a user function is considered checked by the function receiving it
+fn is_bool_checked(
+    compilation_unit: &CompilationUnit,
+    function: &Function,
+    result: &VarId,
+    pc: usize,
+) -> bool {
+    let registry = compilation_unit.registry();
+    let def_use = function.def_use(registry);
+
+    let mut worklist = vec![Value::new(result.clone(), Definition::Statement(pc))];
+    let mut visited = FxHashSet::default();
+    while let Some(value) = worklist.pop() {
+        if !visited.insert(value.clone()) {
+            continue;
+        }
+        for pc in def_use.value_uses(&value) {
+            let invoc = match &function.get_statements()[pc - function.entry_point()] {
+                GenStatement::Invocation(invoc) => invoc,
+                GenStatement::Return(_) => return true,
+            };
+            match registry
+                .get_libfunc(&invoc.libfunc_id)
+                .expect("Library function not found in the registry")
+            {
+                CoreConcreteLibfunc::Drop(_) => (),
+                // Unwrap the Ok variant of the PanicResult
+                libfunc if is_panic_result_match(libfunc) => {
+                    worklist.extend(results_at(&invoc.branches[0].results, pc));
+                }
+                // The bool is transformed by a core function e.g. ==
+                CoreConcreteLibfunc::FunctionCall(f_called)
+                    if compilation_unit
+                        .function_by_name(f_called.function.id.debug_name.as_ref().unwrap())
+                        .is_some_and(|f| *f.ty() == Type::Core) =>
+                {
+                    worklist.extend(results_at(&invoc.branches[0].results, pc));
+                }
+                CoreConcreteLibfunc::FunctionCall(_) => return true,
+                _ if invoc.branches.len() > 1 => return true,
+                _ => worklist.extend(results_at(&invoc.branches[0].results, pc)),
+            }
+        }
+    }
+
+    false
+}
@@ -0,0 +1,236 @@
+use std::collections::{BTreeSet, HashMap, HashSet};
+
+use super::detector::{Confidence, Detector, Impact, Result};
+use crate::core::compilation_unit::CompilationUnit;
+use crate::core::core_unit::CoreUnit;
+use crate::core::function::{Function, Type};
+use crate::utils::called_function_name;
+use cairo_lang_sierra::extensions::core::CoreConcreteLibfunc;
+use cairo_lang_sierra::extensions::starknet::StarkNetConcreteLibfunc;
+use cairo_lang_sierra::program::GenStatement;
+use cairo_lang_starknet::abi::{
+    Item::Function as AbiFunction, Item::Interface as AbiInterface, StateMutability,
+};
+
+#[derive(Default)]
+pub struct UnenforcedView {}
+
+impl Detector for UnenforcedView {
+    fn name(&self) -> &str {
+        "unenforced-view"
+    }
+
+    fn description(&self) -> &str {
+        "Function has view decorator but modifies state"
+    }
+
+    fn confidence(&self) -> Confidence {
+        Confidence::Medium
+    }
+
+    fn impact(&self) -> Impact {
+        Impact::Medium
+    }
+
+    fn run(&self, core: &CoreUnit) -> HashSet<Result> {
+        let mut results: HashSet<Result> = HashSet::new();
+        let compilation_units = core.get_compilation_units();
+        let safe_external_calls = core.get_safe_external_calls();
+        let view_functions = interface_functions_mutability(compilation_units);
+
+        for compilation_unit in compilation_units {
+            for f in compilation_unit
+                .functions_user_defined()
+                .filter(|f| *f.ty() == Type::View)
+            {
+                let reachable = compilation_unit.callgraph().reachable_from(&f.name());
+
+                // The state changing operations done by the function or the functions it calls
+                let state_changes: BTreeSet<String> = reachable
+                    .iter()
+                    .filter_map(|name| compilation_unit.function_by_name(name))
+                    .flat_map(|function| {
+                        self.state_changes(compilation_unit, function, &view_functions)
+                    })
+                    .filter(|operation| {
+                        !safe_external_calls.as_ref().is_some_and(|safe_calls| {
+                            safe_calls
+                                .iter()
+                                .any(|safe| operation.contains(safe.as_str()))
+                        })
+                    })
+                    .collect();
+
+                if !state_changes.is_empty() {
+                    let message = format!(
+                        "The view function {} modifies the state: {}",
+                        f.name(),
+                        state_changes
+                            .into_iter()
+                            .collect::<Vec<String>>()
+                            .join(", ")
+                    );
+                    results.insert(Result {
+                        name: self.name().to_string(),
+                        impact: self.impact(),
+                        confidence: self.confidence(),
+                        message,
+                    });
+                }
+
+                // We don't know if the functions of the interfaces not implemented in the contracts analyzed are views
+                let unknown_calls: BTreeSet<String> = reachable
+                    .iter()
+                    .filter_map(|name| compilation_unit.function_by_name(name))
+                    .flat_map(|function| {
+                        self.unknown_external_calls(compilation_unit, function, &view_functions)
+                    })
+                    .filter(|call| {
+                        !safe_external_calls.as_ref().is_some_and(|safe_calls| {
+                            safe_calls.iter().any(|safe| call.contains(safe.as_str()))
+                        })
+                    })
+                    .collect();
+                if !unknown_calls.is_empty() {
+                    let message = format!(
+                        "The view function {} calls other contracts that may modify the state: {}",
+                        f.name(),
+                        unknown_calls
+                            .into_iter()
+                            .collect::<Vec<String>>()
+                            .join(", ")
+                    );
+                    results.insert(Result {
+                        name: self.name().to_string(),
+                        impact: self.impact(),
+                        confidence: Confidence::Low,
+                        message,
+                    });
+                }
+            }
+        }
+
+        results
+    }
+}
+
+impl UnenforcedView {
+    /// Return the calls done directly in the function to other contracts through an interface not in the ABI of the contracts analyzed
+    fn unknown_external_calls(
+        &self,
+        compilation_unit: &CompilationUnit,
+        function: &Function,
+        view_functions: &HashMap<(String, String), bool>,
+    ) -> Vec<String> {
+        function
+            .external_functions_calls()
+            .chain(function.library_functions_calls())
+            .filter_map(|s| called_function_name(compilation_unit.registry(), s))
+            .filter(|name| {
+                dispatcher_interface_function(name)
+                    .is_some_and(|key| !view_functions.contains_key(&key))
+            })
+            .collect()
+    }
+
+    /// Return the state changing operations done directly in the function
+    /// The calls to other contracts are reported only when the function called is known to not be a view
+    fn state_changes(
+        &self,
+        compilation_unit: &CompilationUnit,
+        function: &Function,
+        view_functions: &HashMap<(String, String), bool>,
+    ) -> Vec<String> {
+        let registry = compilation_unit.registry();
+        let mut operations: Vec<String> = function
+            .storage_vars_written()
+            .chain(function.events_emitted())
+            .filter_map(|s| called_function_name(registry, s))
+            .collect();
+        operations.extend(
+            function
+                .external_functions_calls()
+                .chain(function.library_functions_calls())
+                .filter_map(|s| called_function_name(registry, s))
+                .filter(|name| {
+                    dispatcher_interface_function(name)
+                        .and_then(|key| view_functions.get(&key))
+                        .is_some_and(|is_view| !is_view)
+                }),
+        );
+
+        // The syscalls done by the functions generated by the compiler are already reported with their name
+        if !matches!(
+            function.ty(),
+            Type::External
+                | Type::View
+                | Type::Private
+                | Type::Constructor
+                | Type::L1Handler
+                | Type::Loop
+        ) {
+            return operations;
+        }
+
+        for stmt in function.get_statements() {
+            if let GenStatement::Invocation(invoc) = stmt {
+                let libfunc = compilation_unit
+                    .registry()
+                    .get_libfunc(&invoc.libfunc_id)
+                    .expect("Library function not found in the registry");
+
+                if let CoreConcreteLibfunc::StarkNet(
+                    StarkNetConcreteLibfunc::StorageWrite(_)
+                    | StarkNetConcreteLibfunc::EmitEvent(_)
+                    | StarkNetConcreteLibfunc::ReplaceClass(_)
+                    | StarkNetConcreteLibfunc::Deploy(_)
+                    | StarkNetConcreteLibfunc::SendMessageToL1(_),
+                ) = libfunc
+                {
+                    operations.push(invoc.libfunc_id.debug_name.as_ref().unwrap().to_string());
+                }
+            }
+        }
+
+        operations
+    }
+}
+
+/// Return the interface functions of the contracts analyzed and if they are view functions
+/// The key is the interface path and the function name e.g. (module::IContract, foo)
+fn interface_functions_mutability(
+    compilation_units: &[CompilationUnit],
+) -> HashMap<(String, String), bool> {
+    let mut functions = HashMap::new();
+    for compilation_unit in compilation_units {
+        for item in compilation_unit.abi().clone() {
+            if let AbiInterface(interface) = item {
+                for item in interface.items.iter() {
+                    if let AbiFunction(function) = item {
+                        functions.insert(
+                            (interface.name.clone(), function.name.clone()),
+                            matches!(function.state_mutability, StateMutability::View),
+                        );
+                    }
+                }
+            }
+        }
+    }
+    functions
+}
+
+/// Return the interface path and the function name called by a dispatcher function
+/// e.g. module::IContractDispatcherImpl::foo -> (module::IContract, foo)
+fn dispatcher_interface_function(function_name: &str) -> Option<(String, String)> {
+    let (dispatcher, name) = function_name.rsplit_once("::")?;
+    let (module, dispatcher) = dispatcher.rsplit_once("::")?;
+    let interface = [
+        "SafeLibraryDispatcherImpl",
+        "LibraryDispatcherImpl",
+        "SafeDispatcherImpl",
+        "DispatcherImpl",
+    ]
+    .iter()
+    .find_map(|suffix| dispatcher.strip_suffix(suffix))?;
+    Some((format!("{}::{}", module, interface), name.to_string()))
+}
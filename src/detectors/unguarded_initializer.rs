@@ -0,0 +1,79 @@
+use std::collections::{BTreeSet, HashSet};
+
+use super::detector::{Confidence, Detector, Impact, Result};
+use crate::analysis::initializer::{is_guarded_by_flag, is_initializer};
+use crate::core::core_unit::CoreUnit;
+use crate::core::function::{Function, Type};
+use crate::utils::called_function_name;
+
+#[derive(Default)]
+pub struct UnguardedInitializer {}
+
+impl Detector for UnguardedInitializer {
+    fn name(&self) -> &str {
+        "unguarded-initializer"
+    }
+
+    fn description(&self) -> &str {
+        "Detect external initializers writing the storage without asserting and setting an initialized flag before"
+    }
+
+    fn confidence(&self) -> Confidence {
+        Confidence::Medium
+    }
+
+    fn impact(&self) -> Impact {
+        Impact::High
+    }
+
+    fn run(&self, core: &CoreUnit) -> HashSet<Result> {
+        let mut results: HashSet<Result> = HashSet::new();
+        let compilation_units = core.get_compilation_units();
+
+        for compilation_unit in compilation_units {
+            for f in compilation_unit
+                .functions_user_defined()
+                .filter(|f| *f.ty() == Type::External && is_initializer(&f.name()))
+            {
+                // The functions called by the initializer, itself included
+                let call_tree: Vec<&Function> = compilation_unit
+                    .callgraph()
+                    .reachable_from(&f.name())
+                    .iter()
+                    .filter_map(|name| compilation_unit.function_by_name(name))
+                    .collect();
+
+                let storage_vars_written: BTreeSet<String> = call_tree
+                    .iter()
+                    .flat_map(|function| function.storage_vars_written())
+                    .filter_map(|s| called_function_name(compilation_unit.registry(), s))
+                    .collect();
+                if storage_vars_written.is_empty() {
+                    continue;
+                }
+
+                // A flag set by the initializer must be asserted before writing the storage
+                if is_guarded_by_flag(compilation_unit, f) {
+                    continue;
+                }
+
+                let message = format!(
+                    "The initializer {} can be called more than once, it doesn't assert and set an initialized flag before writing: {}",
+                    f.name(),
+                    storage_vars_written
+                        .into_iter()
+                        .collect::<Vec<String>>()
+                        .join(", ")
+                );
+                results.insert(Result {
+                    name: self.name().to_string(),
+                    impact: self.impact(),
+                    confidence: self.confidence(),
+                    message,
+                });
+            }
+        }
+
+        results
+    }
+}
@@ -0,0 +1,139 @@
+use std::collections::HashSet;
+
+use super::detector::{Confidence, Detector, Impact, Result};
+use crate::analysis::caller_check::is_protected_by_caller_check;
+use crate::core::compilation_unit::CompilationUnit;
+use crate::core::core_unit::CoreUnit;
+use crate::core::function::{Function, Type};
+use crate::utils::{called_function_name, filter_builtins_from_arguments};
+use cairo_lang_sierra::extensions::{core::CoreConcreteLibfunc, starknet::StarkNetConcreteLibfunc};
+use cairo_lang_sierra::program::GenStatement;
+
+#[derive(Default)]
+pub struct UnprotectedUpgrade {}
+
+impl Detector for UnprotectedUpgrade {
+    fn name(&self) -> &str {
+        "unprotected-upgrade"
+    }
+
+    fn description(&self) -> &str {
+        "Detect upgrades with a user controlled class hash without a caller check"
+    }
+
+    fn confidence(&self) -> Confidence {
+        Confidence::Medium
+    }
+
+    fn impact(&self) -> Impact {
+        Impact::High
+    }
+
+    fn run(&self, core: &CoreUnit) -> HashSet<Result> {
+        let mut results: HashSet<Result> = HashSet::new();
+        let compilation_units = core.get_compilation_units();
+
+        for compilation_unit in compilation_units {
+            for f in compilation_unit.functions_user_defined() {
+                for (i, stmt) in f.get_statements().iter().enumerate() {
+                    if let GenStatement::Invocation(invoc) = stmt {
+                        // Get the concrete libfunc called
+                        let libfunc = compilation_unit
+                            .registry()
+                            .get_libfunc(&invoc.libfunc_id)
+                            .expect("Library function not found in the registry");
+
+                        if let CoreConcreteLibfunc::StarkNet(
+                            StarkNetConcreteLibfunc::ReplaceClass(r),
+                        ) = libfunc
+                        {
+                            // The first argument after the builtins is the new class hash
+                            let user_params = filter_builtins_from_arguments(
+                                &r.signature.param_signatures,
+                                invoc.args.clone(),
+                            );
+                            let class_hash = match user_params.first() {
+                                Some(class_hash) => class_hash,
+                                None => continue,
+                            };
+
+                            if !compilation_unit.is_tainted(f.name(), class_hash.clone())
+                                || is_protected_by_caller_check(
+                                    compilation_unit,
+                                    f,
+                                    f.entry_point() + i,
+                                )
+                            {
+                                continue;
+                            }
+
+                            // Report the entry points reaching the upgrade, not the private functions doing it
+                            for entry_point in self.unprotected_entry_points(
+                                compilation_unit,
+                                f,
+                                &mut HashSet::new(),
+                            ) {
+                                let message = format!(
+                                    "The function {} calls replace_class_syscall with a user controlled class hash without checking the caller address before",
+                                    entry_point
+                                );
+                                results.insert(Result {
+                                    name: self.name().to_string(),
+                                    impact: self.impact(),
+                                    confidence: self.confidence(),
+                                    message,
+                                });
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        results
+    }
+}
+
+impl UnprotectedUpgrade {
+    /// Return the external functions calling the function, directly or through other functions,
+    /// without checking the caller address before the calls
+    fn unprotected_entry_points(
+        &self,
+        compilation_unit: &CompilationUnit,
+        function: &Function,
+        visited: &mut HashSet<String>,
+    ) -> Vec<String> {
+        if matches!(function.ty(), Type::External | Type::L1Handler) {
+            return vec![function.name()];
+        }
+        if !visited.insert(function.name()) {
+            return Vec::new();
+        }
+
+        let mut entry_points = Vec::new();
+        for caller in compilation_unit
+            .callgraph()
+            .callers(&function.name())
+            .filter_map(|name| compilation_unit.function_by_name(name))
+        {
+            let unprotected_call = caller.get_statements().iter().enumerate().any(|(i, stmt)| {
+                called_function_name(compilation_unit.registry(), stmt)
+                    .is_some_and(|name| name == function.name())
+                    && !is_protected_by_caller_check(
+                        compilation_unit,
+                        caller,
+                        caller.entry_point() + i,
+                    )
+            });
+            if unprotected_call {
+                entry_points.extend(self.unprotected_entry_points(
+                    compilation_unit,
+                    caller,
+                    visited,
+                ));
+            }
+        }
+
+        entry_points
+    }
+}
@@ -0,0 +1,267 @@
+use std::collections::HashSet;
+
+use super::detector::{Confidence, Detector, Impact, Result};
+use crate::analysis::def_use::{results_at, Definition, Value};
+use crate::analysis::taint::WrapperVariable;
+use crate::core::compilation_unit::CompilationUnit;
+use crate::core::core_unit::CoreUnit;
+use crate::core::function::Function;
+use crate::utils::{filter_builtins_from_arguments, filter_builtins_from_returns};
+use cairo_lang_sierra::extensions::core::CoreConcreteLibfunc;
+use cairo_lang_sierra::extensions::enm::EnumConcreteLibfunc;
+use cairo_lang_sierra::extensions::starknet::StarkNetConcreteLibfunc;
+use cairo_lang_sierra::extensions::structure::StructConcreteLibfunc;
+use cairo_lang_sierra::extensions::ConcreteLibfunc;
+use cairo_lang_sierra::ids::VarId;
+use cairo_lang_sierra::program::GenStatement;
+use fxhash::FxHashSet;
+
+/// Tuple returned by deploy_syscall with the address of the contract deployed and the constructor return data
+const DEPLOY_RESULT_TUPLE: &str =
+    "Tuple<core::starknet::contract_address::ContractAddress, core::array::Span::<core::felt252>>";
+
+#[derive(Default)]
+pub struct UnsafeDeploy {}
+
+impl Detector for UnsafeDeploy {
+    fn name(&self) -> &str {
+        "unsafe-deploy"
+    }
+
+    fn description(&self) -> &str {
+        "Detect deploy_syscall with a user controlled class hash or salt, deploy_from_zero or an ignored address"
+    }
+
+    fn confidence(&self) -> Confidence {
+        Confidence::Medium
+    }
+
+    fn impact(&self) -> Impact {
+        Impact::Medium
+    }
+
+    fn run(&self, core: &CoreUnit) -> HashSet<Result> {
+        let mut results: HashSet<Result> = HashSet::new();
+        let compilation_units = core.get_compilation_units();
+
+        for compilation_unit in compilation_units {
+            for f in compilation_unit.functions_user_defined() {
+                for (i, stmt) in f.get_statements().iter().enumerate() {
+                    let pc = f.entry_point() + i;
+                    let invoc = match stmt {
+                        GenStatement::Invocation(invoc) => invoc,
+                        GenStatement::Return(_) => continue,
+                    };
+                    let libfunc = compilation_unit
+                        .registry()
+                        .get_libfunc(&invoc.libfunc_id)
+                        .expect("Library function not found in the registry");
+                    if !matches!(
+                        libfunc,
+                        CoreConcreteLibfunc::StarkNet(StarkNetConcreteLibfunc::Deploy(_))
+                    ) {
+                        continue;
+                    }
+
+                    let (class_hash, salt, deploy_from_zero) = match filter_builtins_from_arguments(
+                        libfunc.param_signatures(),
+                        invoc.args.clone(),
+                    )
+                    .as_slice()
+                    {
+                        [class_hash, salt, _, deploy_from_zero] => {
+                            (class_hash.clone(), salt.clone(), deploy_from_zero.clone())
+                        }
+                        _ => continue,
+                    };
+
+                    let mut messages = Vec::new();
+                    if compilation_unit.is_tainted(f.name(), class_hash) {
+                        messages.push(format!(
+                            "The function {} deploys a contract with a user controlled class hash",
+                            f.name()
+                        ));
+                    }
+                    if compilation_unit.is_tainted(f.name(), salt.clone())
+                        && !is_bound_to_caller(compilation_unit, f, &salt)
+                    {
+                        messages.push(format!(
+                            "The function {} deploys a contract with a user controlled salt not bound to the caller, the deployment can be front-run",
+                            f.name()
+                        ));
+                    }
+                    if compilation_unit.is_tainted(f.name(), deploy_from_zero.clone())
+                        || is_true(compilation_unit, f, &deploy_from_zero, pc)
+                    {
+                        messages.push(format!(
+                            "The function {} deploys a contract with deploy_from_zero, the address doesn't depend on the deployer",
+                            f.name()
+                        ));
+                    }
+                    // The address is the first value returned after the builtins
+                    let address = filter_builtins_from_returns(
+                        &libfunc.branch_signatures()[0].vars,
+                        invoc.branches[0].results.clone(),
+                    )
+                    .first()
+                    .cloned();
+                    if address
+                        .is_some_and(|address| !is_address_used(compilation_unit, f, &address, pc))
+                    {
+                        messages.push(format!(
+                            "The function {} ignores the address of the contract deployed",
+                            f.name()
+                        ));
+                    }
+
+                    for message in messages {
+                        results.insert(Result {
+                            name: self.name().to_string(),
+                            impact: self.impact(),
+                            confidence: self.confidence(),
+                            message,
+                        });
+                    }
+                }
+            }
+        }
+
+        results
+    }
+}
+
+/// Return true if the salt is derived from the caller address, only the caller can deploy at this address
+fn is_bound_to_caller(
+    compilation_unit: &CompilationUnit,
+    function: &Function,
+    salt: &VarId,
+) -> bool {
+    let taint = match compilation_unit.get_taint(&function.name()) {
+        Some(taint) => taint,
+        None => return false,
+    };
+
+    let callers: FxHashSet<WrapperVariable> = function
+        .get_statements()
+        .iter()
+        .filter_map(|stmt| match stmt {
+            GenStatement::Invocation(invoc) => match compilation_unit
+                .registry()
+                .get_libfunc(&invoc.libfunc_id)
+                .expect("Library function not found in the registry")
+            {
+                CoreConcreteLibfunc::FunctionCall(f_called)
+                    if f_called.function.id.debug_name.as_deref()
+                        == Some("core::starknet::info::get_caller_address") =>
+                {
+                    invoc.branches[0].results.last()
+                }
+                _ => None,
+            },
+            GenStatement::Return(_) => None,
+        })
+        .map(|var| WrapperVariable::new(function.name(), var.id))
+        .collect();
+
+    taint.taints_any_sources(&callers, &WrapperVariable::new(function.name(), salt.id))
+}
+
+/// Return true if the bool is the constant true
+fn is_true(
+    compilation_unit: &CompilationUnit,
+    function: &Function,
+    var: &VarId,
+    pc: usize,
+) -> bool {
+    let registry = compilation_unit.registry();
+    let definitions = function.def_use(registry).value_definitions(var, pc);
+    !definitions.is_empty()
+        && definitions.into_iter().all(|value| match value.definition {
+            Definition::Statement(pc) => {
+                match &function.get_statements()[pc - function.entry_point()] {
+                    GenStatement::Invocation(invoc) => matches!(
+                        registry
+                            .get_libfunc(&invoc.libfunc_id)
+                            .expect("Library function not found in the registry"),
+                        CoreConcreteLibfunc::Enum(EnumConcreteLibfunc::Init(enum_init))
+                            if enum_init.index == 1
+                    ),
+                    GenStatement::Return(_) => false,
+                }
+            }
+            Definition::Parameter => false,
+        })
+}
+
+/// Return true if the address of the contract deployed is used in the success path
+/// i.e. anything other than dropping it, the values wrapping it are followed
+fn is_address_used(
+    compilation_unit: &CompilationUnit,
+    function: &Function,
+    address: &VarId,
+    pc: usize,
+) -> bool {
+    let registry = compilation_unit.registry();
+    let def_use = function.def_use(registry);
+
+    let mut worklist = vec![Value::new(address.clone(), Definition::Statement(pc))];
+    let mut visited = FxHashSet::default();
+    while let Some(value) = worklist.pop() {
+        if !visited.insert(value.clone()) {
+            continue;
+        }
+        for pc in def_use.value_uses(&value) {
+            let invoc = match &function.get_statements()[pc - function.entry_point()] {
+                GenStatement::Invocation(invoc) => invoc,
+                GenStatement::Return(_) => return true,
+            };
+            match registry
+                .get_libfunc(&invoc.libfunc_id)
+                .expect("Library function not found in the registry")
+            {
+                CoreConcreteLibfunc::Drop(_) => (),
+                // The SyscallResult is matched or unwrapped, the success is the first branch
+                CoreConcreteLibfunc::Enum(EnumConcreteLibfunc::Match(enum_match))
+                    if enum_match.signature.param_signatures[0]
+                        .ty
+                        .debug_name
+                        .as_ref()
+                        .is_some_and(|ty| {
+                            ty.starts_with("core::panics::PanicResult")
+                                || ty.starts_with("core::result::Result")
+                        }) =>
+                {
+                    worklist.extend(results_at(&invoc.branches[0].results, pc));
+                }
+                CoreConcreteLibfunc::Struct(StructConcreteLibfunc::Deconstruct(s)) => {
+                    // The constructor return data is not the address
+                    if s.signature.param_signatures[0].ty.debug_name.as_deref()
+                        == Some(DEPLOY_RESULT_TUPLE)
+                    {
+                        worklist.extend(results_at(invoc.branches[0].results.first(), pc));
+                    } else {
+                        worklist.extend(results_at(&invoc.branches[0].results, pc));
+                    }
+                }
+                CoreConcreteLibfunc::Struct(StructConcreteLibfunc::Construct(_))
+                | CoreConcreteLibfunc::Enum(EnumConcreteLibfunc::Init(_)) => {
+                    worklist.extend(results_at(&invoc.branches[0].results, pc));
+                }
+                // e.g. unwrap_syscall
+                CoreConcreteLibfunc::FunctionCall(f_called)
+                    if f_called
+                        .function
+                        .id
+                        .debug_name
+                        .as_ref()
+                        .is_some_and(|name| name.starts_with("core::")) =>
+                {
+                    worklist.extend(results_at(&invoc.branches[0].results, pc));
+                }
+                _ => return true,
+            }
+        }
+    }
+
+    false
+}
@@ -1,14 +1,15 @@
 use std::collections::HashSet;
 
 use super::detector::{Confidence, Detector, Impact, Result};
+use crate::analysis::def_use::{results_at, Value};
 use crate::core::compilation_unit::CompilationUnit;
 use crate::core::core_unit::CoreUnit;
-use crate::core::function::Type;
+use crate::core::function::{Function, Type};
+use crate::utils::{filter_builtins_from_returns, is_panic_result_match};
 use cairo_lang_sierra::extensions::core::CoreConcreteLibfunc;
-use cairo_lang_sierra::extensions::enm::EnumConcreteLibfunc;
 use cairo_lang_sierra::extensions::structure::StructConcreteLibfunc;
 use cairo_lang_sierra::extensions::ConcreteType;
-use cairo_lang_sierra::program::{GenStatement, Statement as SierraStatement, StatementIdx};
+use cairo_lang_sierra::program::Statement as SierraStatement;
 
 #[derive(Default)]
 pub struct UnusedReturn;
@@ -37,128 +38,52 @@ impl Detector for UnusedReturn {
         for compilation_unit in compilation_units {
             for f in compilation_unit.functions_user_defined() {
                 for (i, stmt) in f.get_statements().iter().enumerate() {
-                    if let SierraStatement::Invocation(invoc) = stmt {
-                        // Get the return values from the function
-                        let ret_vars = &invoc.branches[0].results;
-                        // Get the concrete libfunc called
-                        let libfunc = compilation_unit
-                            .registry()
-                            .get_libfunc(&invoc.libfunc_id)
-                            .expect("Library function not found in the registry");
-
-                        if let CoreConcreteLibfunc::FunctionCall(f_called) = libfunc {
-                            // Get the statements after the function call
-                            // if it's a drop it means there is an unused argument
-                            // if it's a struct_deconstruct we need to look at the next statement until it's different from struct_deconstruct
-                            // and check if it's a drop
-                            // if it's a enum_match we will have something like that
-                            //    function_call<user@unused_result::unused_result::UnusedResult::add_1>([6], [7], [8]) -> ([3], [4], [5]);
-                            //    enum_match<core::PanicResult::<(core::felt252,)>>([5]) { fallthrough([9]) 63([10]) };
-                            //    branch_align() -> ();
-                            //    struct_deconstruct<Tuple<felt252>>([9]) -> ([11]);
-                            // followed possibly by others struct_deconstruct and eventually a drop
-                            // Note: we should avoid report when a Unit () is dropped
-
-                            if let Some(f) = compilation_unit.functions().find(|f| {
-                                f.name() == f_called.function.id.debug_name.clone().unwrap()
-                            }) {
-                                // We don't check for unused return in case of Storage functions
-                                // When a loop function is called in sierra and in that function
-                                // an array is emptied with pop_front this array is dropped
-                                // when returning from the function call and it would be incorrectly
-                                // reported as unused-return
-                                if matches!(f.ty(), &Type::Storage | &Type::Loop) {
-                                    continue;
-                                }
-                            } else {
-                                // Should never happen
-                                println!(
-                                    "Unused-return: function not found {}",
-                                    f_called.function.id.debug_name.clone().unwrap()
-                                );
-                                continue;
-                            }
+                    let pc = f.entry_point() + i;
+                    let invoc = match stmt {
+                        SierraStatement::Invocation(invoc) => invoc,
+                        SierraStatement::Return(_) => continue,
+                    };
+                    let f_called = match compilation_unit
+                        .registry()
+                        .get_libfunc(&invoc.libfunc_id)
+                        .expect("Library function not found in the registry")
+                    {
+                        CoreConcreteLibfunc::FunctionCall(f_called) => f_called,
+                        _ => continue,
+                    };
+
+                    // We don't check for unused return in case of Storage functions
+                    // When a loop function is called in sierra and in that function
+                    // an array is emptied with pop_front this array is dropped
+                    // when returning from the function call and it would be incorrectly
+                    // reported as unused-return
+                    match compilation_unit
+                        .function_by_name(f_called.function.id.debug_name.as_ref().unwrap())
+                    {
+                        Some(called) if !matches!(called.ty(), Type::Storage | Type::Loop) => (),
+                        _ => continue,
+                    }
 
-                            let following_stmts = f.get_statements_at(i + 1);
-                            if let SierraStatement::Invocation(invoc) = &following_stmts[0] {
-                                let mut libfunc = compilation_unit
-                                    .registry()
-                                    .get_libfunc(&invoc.libfunc_id)
-                                    .expect("Library function not found in the registry");
-                                // Get the parameters to the instruction for the struct_deconstruct case
-                                let args = &invoc.args;
-                                // Immediate Drop instruction
-                                if let CoreConcreteLibfunc::Drop(drop_libfunc) = libfunc {
-                                    let ty_dropped = compilation_unit
-                                        .registry()
-                                        .get_type(&drop_libfunc.signature.param_signatures[0].ty)
-                                        .expect("Type not found in registry");
-                                    let info = ty_dropped.info();
-                                    // If size is 0 it's the Unit type
-                                    if !info.zero_sized {
-                                        results.insert(Result {
-                                            name: self.name().to_string(),
-                                            impact: self.impact(),
-                                            confidence: self.confidence(),
-                                            message: format!(
-                                            "Return value unused for the function call {} in {}",
-                                            stmt,
-                                            f.name()
-                                        ),
-                                        });
-                                    }
-                                } else if let CoreConcreteLibfunc::Struct(
-                                    StructConcreteLibfunc::Deconstruct(_),
-                                ) = libfunc
-                                {
-                                    let return_variables = invoc.branches[0].results.len();
-
-                                    // Go to the next statement and update the libfunc
-                                    let stmt_to_check = &following_stmts[1..];
-                                    if let SierraStatement::Invocation(invoc) = &stmt_to_check[0] {
-                                        libfunc = compilation_unit
-                                            .registry()
-                                            .get_libfunc(&invoc.libfunc_id)
-                                            .expect("Library function not found in the registry");
-                                        // We want to make sure the struct_deconstruct corresponds to the function's return values, and not any misc. struct cleanup
-                                        if ret_vars.contains(&args[0]) {
-                                            self.iterate_struct_deconstruct(
-                                                compilation_unit,
-                                                &mut results,
-                                                libfunc,
-                                                stmt_to_check,
-                                                stmt,
-                                                &f.name(),
-                                                return_variables,
-                                            );
-                                        }
-                                    }
-                                } else if let CoreConcreteLibfunc::Enum(
-                                    EnumConcreteLibfunc::Match(_),
-                                ) = libfunc
-                                {
-                                    let return_variables = invoc.branches[0].results.len();
-                                    // Jump one statement which is a branch_align and the next one will be a struct_deconstruct
-                                    let stmt_to_check = &following_stmts[2..];
-                                    if let SierraStatement::Invocation(invoc) = &stmt_to_check[0] {
-                                        libfunc = compilation_unit
-                                            .registry()
-                                            .get_libfunc(&invoc.libfunc_id)
-                                            .expect("Library function not found in the registry");
-
-                                        self.iterate_struct_deconstruct(
-                                            compilation_unit,
-                                            &mut results,
-                                            libfunc,
-                                            stmt_to_check,
-                                            stmt,
-                                            &f.name(),
-                                            return_variables,
-                                        );
-                                    }
-                                }
-                            }
-                        }
+                    // The builtins are returned along the values of the function
+                    let returned = filter_builtins_from_returns(
+                        &f_called.signature.branch_signatures[0].vars,
+                        invoc.branches[0].results.clone(),
+                    );
+                    let values = returned_values(compilation_unit, f, results_at(&returned, pc));
+                    if values
+                        .iter()
+                        .any(|value| is_unused(compilation_unit, f, value))
+                    {
+                        results.insert(Result {
+                            name: self.name().to_string(),
+                            impact: self.impact(),
+                            confidence: self.confidence(),
+                            message: format!(
+                                "Return value unused for the function call {} in {}",
+                                stmt,
+                                f.name()
+                            ),
+                        });
                     }
                 }
             }
@@ -167,58 +92,84 @@ impl Detector for UnusedReturn {
     }
 }
 
-impl<'a> UnusedReturn {
-    #[allow(clippy::too_many_arguments)]
-    fn iterate_struct_deconstruct(
-        &self,
-        compilation_unit: &'a CompilationUnit,
-        results: &mut HashSet<Result>,
-        mut libfunc: &'a CoreConcreteLibfunc,
-        mut stmt_to_check: &[GenStatement<StatementIdx>],
-        stmt: &GenStatement<StatementIdx>,
-        function_name: &str,
-        return_variables: usize,
-    ) {
-        let mut return_variables_counter = 0;
-        while let CoreConcreteLibfunc::Struct(StructConcreteLibfunc::Deconstruct(_)) = libfunc {
-            if let SierraStatement::Invocation(invoc) = &stmt_to_check[0] {
-                libfunc = compilation_unit
-                    .registry()
-                    .get_libfunc(&invoc.libfunc_id)
-                    .expect("Library function not found in the registry");
-
-                // If there are other struct deconstruction are not related to the returned variables
-                if return_variables_counter == return_variables {
-                    break;
-                }
+/// Return the values returned by a function call
+/// the PanicResult and the tuples returned are replaced by the values they hold when they are matched or deconstructed
+fn returned_values(
+    compilation_unit: &CompilationUnit,
+    function: &Function,
+    values: impl Iterator<Item = Value>,
+) -> Vec<Value> {
+    let registry = compilation_unit.registry();
+    let def_use = function.def_use(registry);
+    let mut returned = Vec::new();
+    let mut worklist: Vec<Value> = values.collect();
+
+    while let Some(value) = worklist.pop() {
+        let members: Vec<Value> = def_use
+            .value_uses(&value)
+            .into_iter()
+            .flat_map(
+                |pc| match &function.get_statements()[pc - function.entry_point()] {
+                    SierraStatement::Invocation(invoc) => {
+                        let libfunc = registry
+                            .get_libfunc(&invoc.libfunc_id)
+                            .expect("Library function not found in the registry");
+                        match libfunc {
+                            // The first branch holds the values returned without a panic
+                            _ if is_panic_result_match(libfunc) => {
+                                results_at(&invoc.branches[0].results, pc).collect()
+                            }
+                            CoreConcreteLibfunc::Struct(StructConcreteLibfunc::Deconstruct(
+                                struct_type,
+                            )) if struct_type.signature.param_signatures[0]
+                                .ty
+                                .debug_name
+                                .as_ref()
+                                .is_some_and(|ty| ty.starts_with("Tuple<")) =>
+                            {
+                                results_at(&invoc.branches[0].results, pc).collect()
+                            }
+                            _ => Vec::new(),
+                        }
+                    }
+                    SierraStatement::Return(_) => Vec::new(),
+                },
+            )
+            .collect();
+
+        if members.is_empty() {
+            returned.push(value);
+        } else {
+            worklist.extend(members);
+        }
+    }
 
-                return_variables_counter += 1;
+    returned
+}
 
-                stmt_to_check = &stmt_to_check[1..];
-            } else {
-                break;
-            }
-        }
+/// Return true if the value is only dropped
+/// Dropping a Unit () is not reported, its size is 0
+fn is_unused(compilation_unit: &CompilationUnit, function: &Function, value: &Value) -> bool {
+    let registry = compilation_unit.registry();
+    let uses = function.def_use(registry).value_uses(value);
 
-        // If the instruction after all the struct_deconstruct is a drop report unused return value
-        if let CoreConcreteLibfunc::Drop(drop_libfunc) = libfunc {
-            let ty_dropped = compilation_unit
-                .registry()
-                .get_type(&drop_libfunc.signature.param_signatures[0].ty)
-                .expect("Type not found in registry");
-            let info = ty_dropped.info();
-            // If size is 0 it's the Unit type
-            if !info.zero_sized {
-                results.insert(Result {
-                    name: self.name().to_string(),
-                    impact: self.impact(),
-                    confidence: self.confidence(),
-                    message: format!(
-                        "Return value unused for the function call {} in {}",
-                        stmt, function_name
-                    ),
-                });
+    !uses.is_empty()
+        && uses.into_iter().all(|pc| {
+            match &function.get_statements()[pc - function.entry_point()] {
+                SierraStatement::Invocation(invoc) => match registry
+                    .get_libfunc(&invoc.libfunc_id)
+                    .expect("Library function not found in the registry")
+                {
+                    CoreConcreteLibfunc::Drop(drop_libfunc) => {
+                        !registry
+                            .get_type(&drop_libfunc.signature.param_signatures[0].ty)
+                            .expect("Type not found in registry")
+                            .info()
+                            .zero_sized
+                    }
+                    _ => false,
+                },
+                SierraStatement::Return(_) => false,
             }
-        }
-    }
+        })
 }
@@ -1,14 +1,16 @@
 use std::collections::HashSet;
 
 use super::detector::{Confidence, Detector, Impact, Result};
+use crate::analysis::def_use::{results_at, Definition, Value};
 use crate::analysis::taint::WrapperVariable;
 use crate::core::compilation_unit::CompilationUnit;
 use crate::core::core_unit::CoreUnit;
 use crate::core::function::{Function, Type};
+use crate::utils::{called_function_name, is_panic_result_match};
 use cairo_lang_sierra::extensions::array::ArrayConcreteLibfunc;
-use cairo_lang_sierra::extensions::core::{CoreConcreteLibfunc, CoreTypeConcrete};
-use cairo_lang_sierra::program::{GenStatement, Statement as SierraStatement};
-use fxhash::FxHashSet;
+use cairo_lang_sierra::extensions::core::CoreConcreteLibfunc;
+use cairo_lang_sierra::extensions::enm::EnumConcreteLibfunc;
+use cairo_lang_sierra::program::Statement as SierraStatement;
 
 #[derive(Default)]
 pub struct UseAfterPopFront {}
@@ -100,8 +102,7 @@ impl Detector for UseAfterPopFront {
                             let is_used = self.is_used_after_pop_front(
                                 compilation_unit,
                                 function,
-                                bad_array,
-                                *index,
+                                function.entry_point() + *index,
                             );
                             if is_used {
                                 Some((bad_array, collection_type))
@@ -168,278 +169,145 @@ impl Detector for UseAfterPopFront {
 }
 
 impl UseAfterPopFront {
+    /// Return true if the collection left after the pop at pc is used
+    /// i.e. it's appended to, passed to a user defined function, emitted in an event or returned
     fn is_used_after_pop_front(
         &self,
         compilation_unit: &CompilationUnit,
         function: &Function,
-        bad_array: &WrapperVariable,
-        pop_stmt_index: usize,
+        pc: usize,
     ) -> bool {
-        // Check the remaining statements of the function
-        let bad_array_used_in_function =
-            self.check_statements(compilation_unit, function, bad_array, pop_stmt_index);
-
-        // Check if the bad array is sent to any function being called from this function
-        let bad_array_used_in_calls = bad_array_used_in_function
-            || self.check_calls(
-                compilation_unit,
-                function,
-                bad_array,
-                &mut function
-                    .private_functions_calls()
-                    .chain(function.library_functions_calls())
-                    .chain(function.external_functions_calls())
-                    .chain(function.events_emitted()),
-            );
+        let invoc = match &function.get_statements()[pc - function.entry_point()] {
+            SierraStatement::Invocation(invoc) => invoc,
+            SierraStatement::Return(_) => return false,
+        };
+        // The collection left is the first result of every branch
+        let remaining: Vec<Value> = invoc
+            .branches
+            .iter()
+            .filter_map(|branch| branch.results.first())
+            .map(|var| Value::new(var.clone(), Definition::Statement(pc)))
+            .collect();
 
-        // Check the caller of the current function
-        bad_array_used_in_calls || self.check_returns(compilation_unit, function, bad_array)
+        self.is_used(compilation_unit, function, remaining, &mut HashSet::new())
     }
 
-    // Analyse the statements of the function after the pop_front statement
-    // to see if any other element is added to the array.
-    fn check_statements(
+    /// Return true if one of the values or a value holding it is used
+    fn is_used(
         &self,
         compilation_unit: &CompilationUnit,
         function: &Function,
-        bad_array: &WrapperVariable,
-        stmt_index: usize,
+        values: Vec<Value>,
+        visited: &mut HashSet<(String, Value)>,
     ) -> bool {
-        let taint = compilation_unit.get_taint(&function.name()).unwrap();
-
-        // Analyse the statements of the function after the pop_front statement
-        // to see if any other element is added to the array.
-        let bad_array_used = function
-            .get_statements_at(stmt_index)
-            .iter()
-            .filter_map(|stmt| match stmt {
-                SierraStatement::Invocation(invoc) => Some(invoc),
-                _ => None,
-            })
-            .any(|invoc| {
-                let libfunc = compilation_unit
-                    .registry()
-                    .get_libfunc(&invoc.libfunc_id)
-                    .expect("Library function not found in the registry");
+        let registry = compilation_unit.registry();
+        let def_use = function.def_use(registry);
+        let mut worklist = values;
 
-                match libfunc {
-                    CoreConcreteLibfunc::Array(ArrayConcreteLibfunc::Append(_)) => {
-                        let mut sinks = FxHashSet::default();
-                        sinks.insert(WrapperVariable::new(function.name(), invoc.args[0].id));
+        while let Some(value) = worklist.pop() {
+            if !visited.insert((function.name(), value.clone())) {
+                continue;
+            }
 
-                        taint.taints_any_sinks(bad_array, &sinks)
+            for pc in def_use.value_uses(&value) {
+                let invoc = match &function.get_statements()[pc - function.entry_point()] {
+                    SierraStatement::Invocation(invoc) => invoc,
+                    SierraStatement::Return(returned) => {
+                        // A loop function gives the collection back to its caller
+                        if *function.ty() != Type::Loop {
+                            return true;
+                        }
+                        let positions: Vec<usize> = returned
+                            .iter()
+                            .enumerate()
+                            .filter(|(_, var)| def_use.value_definitions(var, pc).contains(&value))
+                            .map(|(i, _)| i)
+                            .collect();
+                        if self.is_used_by_callers(compilation_unit, function, &positions, visited)
+                        {
+                            return true;
+                        }
+                        continue;
                     }
-                    _ => false,
-                }
-            });
+                };
 
-        bad_array_used
-    }
-
-    fn check_calls<'a>(
-        &self,
-        compilation_unit: &CompilationUnit,
-        function: &Function,
-        bad_array: &WrapperVariable,
-        calls: &mut impl Iterator<Item = &'a SierraStatement>,
-    ) -> bool {
-        let taint = compilation_unit.get_taint(&function.name()).unwrap();
-
-        calls.any(|s| {
-            if let GenStatement::Invocation(invoc) = s {
-                let lib_func = compilation_unit
-                    .registry()
+                match registry
                     .get_libfunc(&invoc.libfunc_id)
-                    .expect("Library function not found in the registry");
-
-                if let CoreConcreteLibfunc::FunctionCall(_) = lib_func {
-                    let sinks: FxHashSet<WrapperVariable> = invoc
-                        .args
-                        .iter()
-                        .map(|v| WrapperVariable::new(function.name(), v.id))
-                        .collect();
-
-                    return taint.taints_any_sinks(bad_array, &sinks);
+                    .expect("Library function not found in the registry")
+                {
+                    CoreConcreteLibfunc::Array(ArrayConcreteLibfunc::Append(_)) => return true,
+                    CoreConcreteLibfunc::FunctionCall(f_called) => {
+                        // The loop functions called keep removing elements
+                        let is_use = compilation_unit
+                            .function_by_name(f_called.function.id.debug_name.as_ref().unwrap())
+                            .is_some_and(|called| {
+                                matches!(
+                                    called.ty(),
+                                    Type::Private
+                                        | Type::Event
+                                        | Type::AbiCallContract
+                                        | Type::AbiLibraryCall
+                                )
+                            });
+                        if is_use {
+                            return true;
+                        }
+                    }
+                    // The panic branch of a PanicResult holds the panic data
+                    libfunc if is_panic_result_match(libfunc) => {
+                        worklist.extend(results_at(&invoc.branches[0].results, pc));
+                    }
+                    // The collection is wrapped in another value e.g. a Span or an event
+                    CoreConcreteLibfunc::Struct(_)
+                    | CoreConcreteLibfunc::Enum(
+                        EnumConcreteLibfunc::Init(_) | EnumConcreteLibfunc::Match(_),
+                    )
+                    | CoreConcreteLibfunc::SnapshotTake(_) => {
+                        worklist.extend(
+                            invoc
+                                .branches
+                                .iter()
+                                .flat_map(|branch| results_at(&branch.results, pc)),
+                        );
+                    }
+                    _ => (),
                 }
             }
-            false
-        })
-    }
-
-    // check if the bad array is returned by the function
-    // if yes then check if its a loop function
-    // if yes then we need to check its caller to see if it uses the bad array
-    // if not then its clear usage of a bad array
-    fn check_returns(
-        &self,
-        compilation_unit: &CompilationUnit,
-        function: &Function,
-        bad_array: &WrapperVariable,
-    ) -> bool {
-        // No need to check remaining statements of the caller function
-        // as returning a bad array is already a use of the bad array
-        // if the current function is not a loop function
-        match function.ty() {
-            Type::Loop => self.check_loop_returns(compilation_unit, function, bad_array),
-            _ => self.check_non_loop_returns(compilation_unit, function, bad_array),
         }
-    }
-
-    fn check_loop_returns(
-        &self,
-        compilation_unit: &CompilationUnit,
-        function: &Function,
-        bad_array: &WrapperVariable,
-    ) -> bool {
-        // We can not find the array from the return types of the function
-        // We assume that the array is returned at the same index as it was taken on
-        let return_array_indices: Vec<usize> = function
-            .params_all()
-            .enumerate()
-            .filter_map(|(i, param)| {
-                let param_type = compilation_unit
-                    .registry()
-                    .get_type(&param.ty)
-                    .expect("Type not found in the registry");
-
-                match param_type {
-                    CoreTypeConcrete::Array(_) => Some(i),
-                    span if self.is_core_type_concrete_span(compilation_unit, span) => Some(i),
-                    _ => None,
-                }
-            })
-            .collect();
-
-        // In case the functon is a loop function, we need to check
-        // the remaining statements of the caller function to see if they used the bad array
-        compilation_unit
-            .functions_user_defined()
-            .any(|maybe_caller| {
-                maybe_caller
-                    .loop_functions_calls()
-                    .flat_map(|f| {
-                        if let GenStatement::Invocation(invoc) = f {
-                            let lib_func = compilation_unit
-                                .registry()
-                                .get_libfunc(&invoc.libfunc_id)
-                                .expect("Library function not found in the registry");
 
-                            if let CoreConcreteLibfunc::FunctionCall(f_called) = lib_func {
-                                if function.name().as_str()
-                                    == f_called.function.id.debug_name.as_ref().unwrap()
-                                {
-                                    return return_array_indices
-                                        .iter()
-                                        .map(|i| {
-                                            WrapperVariable::new(
-                                                maybe_caller.name(),
-                                                invoc.branches[0].results[*i].id,
-                                            )
-                                        })
-                                        .collect();
-                                }
-                            }
-                        }
-                        Vec::new()
-                    })
-                    .any(|caller_bad_array| {
-                        self.check_statements(compilation_unit, maybe_caller, &caller_bad_array, 0)
-                            || self.check_calls(
-                                compilation_unit,
-                                function,
-                                bad_array,
-                                &mut maybe_caller
-                                    .private_functions_calls()
-                                    .chain(maybe_caller.library_functions_calls())
-                                    .chain(maybe_caller.external_functions_calls())
-                                    .chain(maybe_caller.events_emitted()),
-                            )
-                    })
-            })
+        false
     }
 
-    fn check_non_loop_returns(
+    /// Return true if a value returned by the loop function at one of the positions is used by its callers
+    fn is_used_by_callers(
         &self,
         compilation_unit: &CompilationUnit,
         function: &Function,
-        bad_array: &WrapperVariable,
+        positions: &[usize],
+        visited: &mut HashSet<(String, Value)>,
     ) -> bool {
-        let taint = compilation_unit.get_taint(&function.name()).unwrap();
-
-        let return_array_indices: Vec<usize> = function
-            .returns_all()
-            .enumerate()
-            .flat_map(|(i, r)| {
-                let return_type = compilation_unit
-                    .registry()
-                    .get_type(r)
-                    .expect("Type not found in the registry");
-
-                match return_type {
-                    CoreTypeConcrete::Array(_) => Some(i),
-                    span if self.is_core_type_concrete_span(compilation_unit, span) => Some(i),
-                    _ => None,
-                }
-            })
-            .collect();
-
-        // Not returning any array
-        if return_array_indices.is_empty() {
-            return false;
-        }
-
-        // It is not required because taint analysis adds all the arugments as
-        // tainters of the all the return values. Added it in case the taint
-        // analysis is improved later on to be more granular.
-        let returned_bad_arrays: Vec<WrapperVariable> = function
-            .get_statements()
-            .iter()
-            .flat_map(|s| {
-                if let GenStatement::Return(return_vars) = s {
-                    let sinks: FxHashSet<WrapperVariable> = return_vars
+        compilation_unit.functions_user_defined().any(|caller| {
+            let returned: Vec<Value> = caller
+                .get_statements()
+                .iter()
+                .enumerate()
+                .filter(|(_, stmt)| {
+                    called_function_name(compilation_unit.registry(), stmt)
+                        .is_some_and(|name| name == function.name())
+                })
+                .flat_map(|(i, stmt)| match stmt {
+                    SierraStatement::Invocation(invoc) => positions
                         .iter()
-                        .map(|v| WrapperVariable::new(function.name(), v.id))
-                        .collect();
-
-                    return taint.taints_any_sinks_variable(bad_array, &sinks);
-                }
-                Vec::new()
-            })
-            .collect();
-
-        !returned_bad_arrays.is_empty()
-    }
-
-    // The Span is not a Core Sierra type, it is defined in the corelib as a Struct
-    // Therefore we can not match it against CoreTypeConcrete::Span directly
-    fn is_core_type_concrete_span(
-        &self,
-        compilation_unit: &CompilationUnit,
-        maybe_span: &CoreTypeConcrete,
-    ) -> bool {
-        match maybe_span {
-            CoreTypeConcrete::Struct(struct_type) => match &struct_type.members[..] {
-                [maybe_snapshot, ..] => {
-                    let maybe_snapshot_type = compilation_unit
-                        .registry()
-                        .get_type(maybe_snapshot)
-                        .expect("Type not found in the registry");
-
-                    match maybe_snapshot_type {
-                        CoreTypeConcrete::Snapshot(maybe_array) => {
-                            let maybe_array_type = compilation_unit
-                                .registry()
-                                .get_type(&maybe_array.ty)
-                                .expect("Type not found in the registry");
+                        .filter_map(|position| invoc.branches[0].results.get(*position))
+                        .map(|var| {
+                            Value::new(var.clone(), Definition::Statement(caller.entry_point() + i))
+                        })
+                        .collect(),
+                    SierraStatement::Return(_) => Vec::new(),
+                })
+                .collect();
 
-                            matches!(maybe_array_type, CoreTypeConcrete::Array(_))
-                        }
-                        _ => false,
-                    }
-                }
-                _ => false,
-            },
-            _ => false,
-        }
+            !returned.is_empty() && self.is_used(compilation_unit, caller, returned, visited)
+        })
     }
 }
@@ -0,0 +1,262 @@
+use std::collections::{BTreeSet, HashSet};
+
+use super::detector::{Confidence, Detector, Impact, Result};
+use crate::analysis::def_use::Definition;
+use crate::analysis::panics::always_panics_from;
+use crate::analysis::taint::WrapperVariable;
+use crate::core::cfg::Cfg;
+use crate::core::compilation_unit::CompilationUnit;
+use crate::core::core_unit::CoreUnit;
+use crate::core::function::{Function, Type};
+use crate::utils::{filter_builtins_from_arguments, is_panic_result_match};
+use cairo_lang_sierra::extensions::core::CoreConcreteLibfunc;
+use cairo_lang_sierra::ids::VarId;
+use cairo_lang_sierra::program::GenStatement;
+use fxhash::FxHashSet;
+
+#[derive(Default)]
+pub struct ViewPanic {}
+
+impl Detector for ViewPanic {
+    fn name(&self) -> &str {
+        "view-panic"
+    }
+
+    fn description(&self) -> &str {
+        "Detect view functions panicking on a condition controlled by the user"
+    }
+
+    fn confidence(&self) -> Confidence {
+        Confidence::Low
+    }
+
+    fn impact(&self) -> Impact {
+        Impact::Low
+    }
+
+    fn run(&self, core: &CoreUnit) -> HashSet<Result> {
+        let mut results: HashSet<Result> = HashSet::new();
+        let compilation_units = core.get_compilation_units();
+
+        for compilation_unit in compilation_units {
+            for f in compilation_unit
+                .functions_user_defined()
+                .filter(|f| *f.ty() == Type::View)
+            {
+                // The first parameter is the contract state
+                let user_params: FxHashSet<WrapperVariable> = f
+                    .params()
+                    .skip(1)
+                    .map(|param| WrapperVariable::new(f.name(), param.id.id))
+                    .collect();
+                if user_params.is_empty() {
+                    continue;
+                }
+
+                let mut panicking_functions = BTreeSet::new();
+                find_user_panics(
+                    compilation_unit,
+                    f,
+                    &user_params,
+                    &mut HashSet::new(),
+                    &mut panicking_functions,
+                );
+                if panicking_functions.is_empty() {
+                    continue;
+                }
+
+                let message = format!(
+                    "The view function {} can panic on a condition controlled by the user in {}",
+                    f.name(),
+                    panicking_functions
+                        .into_iter()
+                        .collect::<Vec<String>>()
+                        .join(", ")
+                );
+                results.insert(Result {
+                    name: self.name().to_string(),
+                    impact: self.impact(),
+                    confidence: self.confidence(),
+                    message,
+                });
+            }
+        }
+
+        results
+    }
+}
+
+/// Find the functions with a branch on a value derived from the sources where a branch always ends with a panic
+/// in the function and in the private functions it calls
+fn find_user_panics(
+    compilation_unit: &CompilationUnit,
+    function: &Function,
+    sources: &FxHashSet<WrapperVariable>,
+    checked_private_functions: &mut HashSet<String>,
+    panicking_functions: &mut BTreeSet<String>,
+) {
+    let taint = match compilation_unit.get_taint(&function.name()) {
+        Some(taint) => taint,
+        None => return,
+    };
+    let is_tainted = |var: &VarId| {
+        taint.taints_any_sources(sources, &WrapperVariable::new(function.name(), var.id))
+    };
+    let registry = compilation_unit.registry();
+    let cfg = function.get_cfg();
+
+    for bb in cfg.get_basic_blocks() {
+        let (invoc, pc) = match bb.last_instruction() {
+            Some(instruction) => match instruction.get_statement() {
+                GenStatement::Invocation(invoc) if invoc.branches.len() > 1 => {
+                    (invoc, instruction.get_pc())
+                }
+                _ => continue,
+            },
+            None => continue,
+        };
+        // Matching a PanicResult only propagates the panic of the function called
+        // unless it's a core function panicking on a user controlled value e.g. an overflow
+        if is_panic_result_match(
+            registry
+                .get_libfunc(&invoc.libfunc_id)
+                .expect("Library function not found in the registry"),
+        ) && !is_core_call_result(compilation_unit, function, &invoc.args[0], pc, &is_tainted)
+        {
+            continue;
+        }
+        if !invoc.args.iter().any(is_tainted) {
+            continue;
+        }
+
+        let panics = bb.get_outgoing_basic_blocks().iter().any(|bb_id| {
+            cfg.get_basic_block(*bb_id)
+                .and_then(|target| target.first_instruction())
+                .is_some_and(|target| always_panics_from(function, registry, target.get_pc()))
+        });
+        if panics {
+            panicking_functions.insert(function.name());
+        }
+    }
+
+    // The panic of a core function returned without matching it e.g. an overflow in the returned value
+    let returns_core_panic =
+        function
+            .get_statements()
+            .iter()
+            .enumerate()
+            .any(|(i, stmt)| match stmt {
+                GenStatement::Return(vars) => vars.iter().any(|var| {
+                    is_core_call_result(
+                        compilation_unit,
+                        function,
+                        var,
+                        function.entry_point() + i,
+                        &is_tainted,
+                    )
+                }),
+                GenStatement::Invocation(_) => false,
+            });
+    if returns_core_panic {
+        panicking_functions.insert(function.name());
+    }
+
+    // Follow the values passed to private functions and loops
+    for stmt in function
+        .private_functions_calls()
+        .chain(function.loop_functions_calls())
+    {
+        let invoc = match stmt {
+            GenStatement::Invocation(invoc) => invoc,
+            GenStatement::Return(_) => continue,
+        };
+        let f_called = match registry
+            .get_libfunc(&invoc.libfunc_id)
+            .expect("Library function not found in the registry")
+        {
+            CoreConcreteLibfunc::FunctionCall(f_called) => f_called,
+            _ => continue,
+        };
+        let private_function = match f_called
+            .function
+            .id
+            .debug_name
+            .as_ref()
+            .and_then(|name| compilation_unit.function_by_name(name))
+        {
+            Some(private_function) => private_function,
+            None => continue,
+        };
+        if !checked_private_functions.insert(private_function.name()) {
+            continue;
+        }
+
+        let user_args = filter_builtins_from_arguments(
+            &f_called.signature.param_signatures,
+            invoc.args.clone(),
+        );
+        let private_sources: FxHashSet<WrapperVariable> = user_args
+            .iter()
+            .zip(private_function.params())
+            .filter(|(arg, _)| is_tainted(arg))
+            .map(|(_, param)| WrapperVariable::new(private_function.name(), param.id.id))
+            .collect();
+        if !private_sources.is_empty() {
+            find_user_panics(
+                compilation_unit,
+                private_function,
+                &private_sources,
+                checked_private_functions,
+                panicking_functions,
+            );
+        }
+    }
+}
+
+/// Return true if the value used at pc is the PanicResult returned by a core function
+/// called with a value derived from the sources
+fn is_core_call_result(
+    compilation_unit: &CompilationUnit,
+    function: &Function,
+    var: &VarId,
+    pc: usize,
+    is_tainted: &dyn Fn(&VarId) -> bool,
+) -> bool {
+    let registry = compilation_unit.registry();
+    function
+        .def_use(registry)
+        .value_definitions(var, pc)
+        .iter()
+        .any(|value| match value.definition {
+            Definition::Statement(def_pc) => {
+                let call = match &function.get_statements()[def_pc - function.entry_point()] {
+                    GenStatement::Invocation(call) => call,
+                    GenStatement::Return(_) => return false,
+                };
+                let f_called = match registry
+                    .get_libfunc(&call.libfunc_id)
+                    .expect("Library function not found in the registry")
+                {
+                    CoreConcreteLibfunc::FunctionCall(f_called) => f_called,
+                    _ => return false,
+                };
+                let returns_panic_result = f_called.signature.branch_signatures[0]
+                    .vars
+                    .last()
+                    .and_then(|var| var.ty.debug_name.as_deref())
+                    .is_some_and(|ty| ty.starts_with("core::panics::PanicResult"));
+                // unwrap_syscall panics when the syscall fails, not on the values passed to it
+                let is_core_function = f_called
+                    .function
+                    .id
+                    .debug_name
+                    .as_ref()
+                    .filter(|name| !name.contains("SyscallResultTrait"))
+                    .and_then(|name| compilation_unit.function_by_name(name))
+                    .is_some_and(|called| *called.ty() == Type::Core);
+
+                returns_panic_result && is_core_function && call.args.iter().any(is_tainted)
+            }
+            Definition::Parameter => false,
+        })
+}
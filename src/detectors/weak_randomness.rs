@@ -0,0 +1,198 @@
+use std::collections::HashSet;
+
+use super::detector::{Confidence, Detector, Impact, Result};
+use crate::analysis::randomness::{is_randomness_sink, unpredictable_values};
+use crate::analysis::taint::WrapperVariable;
+use crate::core::compilation_unit::CompilationUnit;
+use crate::core::core_unit::CoreUnit;
+use crate::core::function::Function;
+use crate::utils::filter_builtins_from_arguments;
+use cairo_lang_sierra::extensions::core::CoreConcreteLibfunc;
+use cairo_lang_sierra::extensions::structure::StructConcreteLibfunc;
+use cairo_lang_sierra::extensions::ConcreteLibfunc;
+use cairo_lang_sierra::program::GenStatement;
+use fxhash::FxHashSet;
+
+/// Structs holding information known before the transaction is executed
+const PREDICTABLE_STRUCTS: [&str; 6] = [
+    "core::starknet::info::BlockInfo",
+    "core::starknet::info::TxInfo",
+    "core::starknet::info::v2::TxInfo",
+    "core::starknet::info::ExecutionInfo",
+    "core::starknet::info::v2::ExecutionInfo",
+    "core::starknet::info::v2::ResourceBounds",
+];
+
+#[derive(Default)]
+pub struct WeakRandomness {}
+
+impl Detector for WeakRandomness {
+    fn name(&self) -> &str {
+        "weak-randomness"
+    }
+
+    fn description(&self) -> &str {
+        "Detect hashes of block, transaction or caller information used as a source of randomness"
+    }
+
+    fn confidence(&self) -> Confidence {
+        Confidence::Medium
+    }
+
+    fn impact(&self) -> Impact {
+        Impact::Medium
+    }
+
+    fn run(&self, core: &CoreUnit) -> HashSet<Result> {
+        let mut results: HashSet<Result> = HashSet::new();
+        let compilation_units = core.get_compilation_units();
+
+        for compilation_unit in compilation_units {
+            for f in compilation_unit.functions_user_defined() {
+                let taint = match compilation_unit.get_taint(&f.name()) {
+                    Some(taint) => taint,
+                    None => continue,
+                };
+
+                let predictable = predictable_values(compilation_unit, f);
+                if predictable.is_empty() {
+                    continue;
+                }
+                let unpredictable = unpredictable_values(compilation_unit, f);
+
+                // The hashes computed only from predictable values and constants
+                let weak_hashes: FxHashSet<WrapperVariable> = f
+                    .get_statements()
+                    .iter()
+                    .filter_map(|stmt| match stmt {
+                        GenStatement::Invocation(invoc) => Some(invoc),
+                        GenStatement::Return(_) => None,
+                    })
+                    .filter(|invoc| {
+                        let libfunc = compilation_unit
+                            .registry()
+                            .get_libfunc(&invoc.libfunc_id)
+                            .expect("Library function not found in the registry");
+                        let inputs: Vec<WrapperVariable> = match libfunc {
+                            CoreConcreteLibfunc::Pedersen(_) | CoreConcreteLibfunc::Poseidon(_) => {
+                                filter_builtins_from_arguments(
+                                    libfunc.param_signatures(),
+                                    invoc.args.clone(),
+                                )
+                            }
+                            CoreConcreteLibfunc::FunctionCall(f_called)
+                                if f_called.function.id.debug_name.as_ref().is_some_and(
+                                    |name| {
+                                        name.starts_with("core::pedersen::")
+                                            || name.starts_with("core::poseidon::")
+                                    },
+                                ) =>
+                            {
+                                filter_builtins_from_arguments(
+                                    &f_called.signature.param_signatures,
+                                    invoc.args.clone(),
+                                )
+                            }
+                            _ => return false,
+                        }
+                        .into_iter()
+                        .map(|arg| WrapperVariable::new(f.name(), arg.id))
+                        .collect();
+
+                        inputs
+                            .iter()
+                            .any(|input| taint.taints_any_sources(&predictable, input))
+                            && !inputs.iter().any(|input| {
+                                unpredictable.contains(input)
+                                    || taint.taints_any_sources(&unpredictable, input)
+                            })
+                    })
+                    .flat_map(|invoc| invoc.branches[0].results.iter())
+                    .map(|var| WrapperVariable::new(f.name(), var.id))
+                    .collect();
+                if weak_hashes.is_empty() {
+                    continue;
+                }
+
+                let is_used_as_randomness = f.get_statements().iter().any(|stmt| match stmt {
+                    GenStatement::Invocation(invoc) => {
+                        let libfunc = compilation_unit
+                            .registry()
+                            .get_libfunc(&invoc.libfunc_id)
+                            .expect("Library function not found in the registry");
+                        is_randomness_sink(libfunc)
+                            && invoc.args.iter().any(|arg| {
+                                taint.taints_any_sources(
+                                    &weak_hashes,
+                                    &WrapperVariable::new(f.name(), arg.id),
+                                )
+                            })
+                    }
+                    GenStatement::Return(_) => false,
+                });
+                if !is_used_as_randomness {
+                    continue;
+                }
+
+                let message = format!(
+                    "The function {} uses a hash of block, transaction or caller information as a source of randomness",
+                    f.name()
+                );
+                results.insert(Result {
+                    name: self.name().to_string(),
+                    impact: self.impact(),
+                    confidence: self.confidence(),
+                    message,
+                });
+            }
+        }
+
+        results
+    }
+}
+
+/// Return the values of the block and transaction information and the caller address read in the function
+fn predictable_values(
+    compilation_unit: &CompilationUnit,
+    function: &Function,
+) -> FxHashSet<WrapperVariable> {
+    let mut values = FxHashSet::default();
+
+    for stmt in function.get_statements() {
+        if let GenStatement::Invocation(invoc) = stmt {
+            let libfunc = compilation_unit
+                .registry()
+                .get_libfunc(&invoc.libfunc_id)
+                .expect("Library function not found in the registry");
+
+            let is_predictable = match libfunc {
+                CoreConcreteLibfunc::Struct(StructConcreteLibfunc::Deconstruct(struct_type)) => {
+                    PREDICTABLE_STRUCTS.contains(
+                        &struct_type.signature.param_signatures[0]
+                            .ty
+                            .to_string()
+                            .as_str(),
+                    )
+                }
+                // get_caller_address, get_block_timestamp, get_tx_info...
+                CoreConcreteLibfunc::FunctionCall(f_called) => f_called
+                    .function
+                    .id
+                    .debug_name
+                    .as_ref()
+                    .is_some_and(|name| name.starts_with("core::starknet::info::get_")),
+                _ => false,
+            };
+            if is_predictable {
+                values.extend(
+                    invoc.branches[0]
+                        .results
+                        .iter()
+                        .map(|var| WrapperVariable::new(function.name(), var.id)),
+                );
+            }
+        }
+    }
+
+    values
+}
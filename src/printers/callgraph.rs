@@ -2,8 +2,6 @@ use super::printer::{Filter, PrintOpts, Printer, Result};
 use crate::core::compilation_unit::CompilationUnit;
 use crate::core::function::Type;
 use crate::core::{core_unit::CoreUnit, function::Function};
-use cairo_lang_sierra::extensions::core::CoreConcreteLibfunc;
-use cairo_lang_sierra::program::Statement as SierraStatement;
 use graphviz_rust::dot_generator::*;
 use graphviz_rust::dot_structures::*;
 use graphviz_rust::printer::{DotPrinter, PrinterContext};
@@ -84,69 +82,42 @@ impl CallgraphPrinter {
         self.add_contract_subgraphs(calling_fn_name, tracked_contracts, &mut tracked_fns);
 
         // Iterate over function calls and create subgraphs + edges
-        let private_functions_call_list = f.private_functions_calls();
-        let external_call_list = f.external_functions_calls();
-        let library_functions_call_list = f.library_functions_calls();
+        let callgraph = compilation_unit.callgraph();
         self.create_subgraphs_and_edges(
             calling_fn_name,
-            private_functions_call_list,
+            callgraph
+                .callees(name)
+                // Loop functions are drawn as part of the function they are called from
+                .filter(|f_called| {
+                    compilation_unit
+                        .function_by_name(f_called)
+                        .is_some_and(|f_called| *f_called.ty() != Type::Loop)
+                })
+                .chain(callgraph.external_calls(name))
+                .chain(callgraph.library_calls(name)),
             tracked_contracts,
             &mut tracked_fns,
-            compilation_unit,
-            graph,
-        );
-
-        self.create_subgraphs_and_edges(
-            calling_fn_name,
-            external_call_list,
-            tracked_contracts,
-            &mut tracked_fns,
-            compilation_unit,
-            graph,
-        );
-        self.create_subgraphs_and_edges(
-            calling_fn_name,
-            library_functions_call_list,
-            tracked_contracts,
-            &mut tracked_fns,
-            compilation_unit,
             graph,
         );
     }
     fn create_subgraphs_and_edges<'a>(
         &self,
         calling_fn_name: &str,
-        call_list: impl Iterator<Item = &'a SierraStatement>,
+        called_functions: impl Iterator<Item = &'a String>,
         tracked_contracts: &mut HashMap<String, Subgraph>,
         tracked_fns: &mut HashSet<String>,
-        compilation_unit: &CompilationUnit,
         graph: &mut Graph,
     ) {
         // Track edges in order to avoid duplicates
-        let mut tracked_edges: HashSet<String> = HashSet::new();
-        for call in call_list {
-            if let SierraStatement::Invocation(invoc) = call {
-                let libfunc = compilation_unit
-                    .registry()
-                    .get_libfunc(&invoc.libfunc_id)
-                    .expect("Library not found in core registry");
-                if let CoreConcreteLibfunc::FunctionCall(f_called) = libfunc {
-                    let func_name = f_called
-                        .function
-                        .id
-                        .debug_name
-                        .as_ref()
-                        .unwrap()
-                        .to_string();
-                    if tracked_edges.contains(&func_name) {
-                        continue;
-                    }
-
-                    self.add_contract_subgraphs(&func_name, tracked_contracts, tracked_fns);
-                    graph.add_stmt(Stmt::Edge(edge!(node_id!(format!("\"{}\"",calling_fn_name)) => node_id!(format!("\"{}\"", &func_name)))));
-                    tracked_edges.insert(func_name);
-                }
+        let mut tracked_edges: HashSet<&String> = HashSet::new();
+        for func_name in called_functions {
+            if tracked_edges.contains(func_name) {
+                continue;
             }
+
+            self.add_contract_subgraphs(func_name, tracked_contracts, tracked_fns);
+            graph.add_stmt(Stmt::Edge(edge!(node_id!(format!("\"{}\"",calling_fn_name)) => node_id!(format!("\"{}\"", func_name)))));
+            tracked_edges.insert(func_name);
         }
     }
     fn add_contract_subgraphs(
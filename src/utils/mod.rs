@@ -1,5 +1,15 @@
+use cairo_lang_sierra::extensions::casts::CastConcreteLibfunc;
+use cairo_lang_sierra::extensions::core::{CoreConcreteLibfunc, CoreLibfunc, CoreType};
+use cairo_lang_sierra::extensions::enm::EnumConcreteLibfunc;
+use cairo_lang_sierra::extensions::int::signed::SintConcrete;
+use cairo_lang_sierra::extensions::int::signed128::Sint128Concrete;
+use cairo_lang_sierra::extensions::int::unsigned::UintConcrete;
+use cairo_lang_sierra::extensions::int::unsigned128::Uint128Concrete;
 use cairo_lang_sierra::extensions::lib_func::{OutputVarInfo, ParamSignature};
+use cairo_lang_sierra::extensions::starknet::StarkNetConcreteLibfunc;
 use cairo_lang_sierra::ids::VarId;
+use cairo_lang_sierra::program::{BranchInfo, BranchTarget, GenStatement, Statement};
+use cairo_lang_sierra::program_registry::ProgramRegistry;
 
 pub const BUILTINS: [&str; 8] = [
     "Pedersen",
@@ -36,7 +46,6 @@ pub fn filter_builtins_from_arguments(
 }
 
 /// Filter the builtins from the return variables and returns only the user defined variables
-#[allow(dead_code)]
 pub fn filter_builtins_from_returns(
     signature: &[OutputVarInfo],
     returns: Vec<VarId>,
@@ -51,6 +60,88 @@ pub fn filter_builtins_from_returns(
         .collect()
 }
 
+/// Return true if the libfunc converts a value to a narrower type and branches on failure
+pub fn is_narrowing_libfunc(libfunc: &CoreConcreteLibfunc) -> bool {
+    matches!(
+        libfunc,
+        CoreConcreteLibfunc::Uint8(UintConcrete::FromFelt252(_))
+            | CoreConcreteLibfunc::Uint16(UintConcrete::FromFelt252(_))
+            | CoreConcreteLibfunc::Uint32(UintConcrete::FromFelt252(_))
+            | CoreConcreteLibfunc::Uint64(UintConcrete::FromFelt252(_))
+            | CoreConcreteLibfunc::Uint128(Uint128Concrete::FromFelt252(_))
+            | CoreConcreteLibfunc::Sint8(SintConcrete::FromFelt252(_))
+            | CoreConcreteLibfunc::Sint16(SintConcrete::FromFelt252(_))
+            | CoreConcreteLibfunc::Sint32(SintConcrete::FromFelt252(_))
+            | CoreConcreteLibfunc::Sint64(SintConcrete::FromFelt252(_))
+            | CoreConcreteLibfunc::Sint128(Sint128Concrete::FromFelt252(_))
+            | CoreConcreteLibfunc::Cast(CastConcreteLibfunc::Downcast(_))
+            | CoreConcreteLibfunc::StarkNet(
+                StarkNetConcreteLibfunc::ContractAddressTryFromFelt252(_)
+                    | StarkNetConcreteLibfunc::ClassHashTryFromFelt252(_)
+                    | StarkNetConcreteLibfunc::StorageAddressTryFromFelt252(_)
+            )
+    )
+}
+
+/// Return true if the core function converts a value to a narrower type returning an Option
+pub fn is_narrowing_function(name: &str) -> bool {
+    name.starts_with("core::")
+        && (name.ends_with("_try_from_felt252")
+            || name.contains("downcast")
+            || (name.contains("TryInto") && name.ends_with("::try_into")))
+}
+
+/// Return the pc where the branch of the statement at pc jumps
+pub fn branch_target(branch: &BranchInfo, pc: usize) -> usize {
+    match branch.target {
+        BranchTarget::Fallthrough => pc + 1,
+        BranchTarget::Statement(target) => target.0,
+    }
+}
+
+/// Return the name of the function called by the statement if it's a function call
+pub fn called_function_name(
+    registry: &ProgramRegistry<CoreType, CoreLibfunc>,
+    statement: &Statement,
+) -> Option<String> {
+    if let GenStatement::Invocation(invoc) = statement {
+        let libfunc = registry
+            .get_libfunc(&invoc.libfunc_id)
+            .expect("Library function not found in the registry");
+        if let CoreConcreteLibfunc::FunctionCall(f_called) = libfunc {
+            return f_called
+                .function
+                .id
+                .debug_name
+                .as_ref()
+                .map(|n| n.to_string());
+        }
+    }
+    None
+}
+
+/// Return the name of the storage variable accessed by a storage function e.g. module::var::read -> module::var
+pub fn storage_var_name(function_name: &str) -> &str {
+    function_name
+        .rsplit_once("::")
+        .map_or(function_name, |(module, _)| module)
+}
+
+/// Return true if the libfunc matches a PanicResult
+/// i.e. it only checks if a function called panicked
+pub fn is_panic_result_match(libfunc: &CoreConcreteLibfunc) -> bool {
+    match libfunc {
+        CoreConcreteLibfunc::Enum(EnumConcreteLibfunc::Match(enum_match)) => {
+            enum_match.signature.param_signatures[0]
+                .ty
+                .debug_name
+                .as_ref()
+                .is_some_and(|ty| ty.starts_with("core::panics::PanicResult"))
+        }
+        _ => false,
+    }
+}
+
 /// Get a number as input and return the ordinal representation
 pub fn number_to_ordinal(n: u64) -> String {
     let s = n.to_string();
@@ -0,0 +1,128 @@
+#[starknet::contract(account)]
+mod BadAccount {
+    use starknet::account::Call;
+    use starknet::call_contract_syscall;
+
+    #[storage]
+    struct Storage {
+        nonce: felt252,
+    }
+
+    #[external(v0)]
+    fn __validate__(self: @ContractState, calls: Array<Call>) -> felt252 {
+        starknet::VALIDATED
+    }
+
+    #[external(v0)]
+    fn __execute__(ref self: ContractState, calls: Array<Call>) -> Array<Span<felt252>> {
+        self.nonce.write(self.nonce.read() + 1);
+        execute_calls(calls)
+    }
+
+    fn execute_calls(calls: Array<Call>) -> Array<Span<felt252>> {
+        let mut res = ArrayTrait::new();
+        let call = calls.at(0);
+        let result = call_contract_syscall(*call.to, *call.selector, *call.calldata)
+            .unwrap();
+        res.append(result);
+        res
+    }
+}
+
+#[starknet::contract(account)]
+mod GoodAccount {
+    use starknet::account::Call;
+    use starknet::{call_contract_syscall, get_caller_address};
+    use core::zeroable::Zeroable;
+
+    #[storage]
+    struct Storage {
+        nonce: felt252,
+    }
+
+    #[external(v0)]
+    fn __validate__(self: @ContractState, calls: Array<Call>) -> felt252 {
+        starknet::VALIDATED
+    }
+
+    #[external(v0)]
+    fn __execute__(ref self: ContractState, calls: Array<Call>) -> Array<Span<felt252>> {
+        assert(get_caller_address().is_zero(), 'Invalid caller');
+        self.nonce.write(self.nonce.read() + 1);
+        execute_calls(calls)
+    }
+
+    fn execute_calls(calls: Array<Call>) -> Array<Span<felt252>> {
+        let mut res = ArrayTrait::new();
+        let call = calls.at(0);
+        let result = call_contract_syscall(*call.to, *call.selector, *call.calldata)
+            .unwrap();
+        res.append(result);
+        res
+    }
+}
+
+#[starknet::contract(account)]
+mod BadOwnerAccount {
+    use starknet::account::Call;
+    use starknet::{ContractAddress, call_contract_syscall, get_caller_address};
+
+    #[storage]
+    struct Storage {
+        owner: ContractAddress,
+    }
+
+    #[external(v0)]
+    fn __validate__(self: @ContractState, calls: Array<Call>) -> felt252 {
+        starknet::VALIDATED
+    }
+
+    #[external(v0)]
+    fn __execute__(ref self: ContractState, calls: Array<Call>) -> Array<Span<felt252>> {
+        assert(get_caller_address() == self.owner.read(), 'Invalid caller');
+        execute_calls(calls)
+    }
+
+    fn execute_calls(calls: Array<Call>) -> Array<Span<felt252>> {
+        let mut res = ArrayTrait::new();
+        let call = calls.at(0);
+        let result = call_contract_syscall(*call.to, *call.selector, *call.calldata)
+            .unwrap();
+        res.append(result);
+        res
+    }
+}
+
+#[starknet::contract(account)]
+mod BadBranchAccount {
+    use starknet::account::Call;
+    use starknet::{call_contract_syscall, get_caller_address};
+    use core::zeroable::Zeroable;
+
+    #[storage]
+    struct Storage {
+        nonce: felt252,
+    }
+
+    #[external(v0)]
+    fn __validate__(self: @ContractState, calls: Array<Call>) -> felt252 {
+        starknet::VALIDATED
+    }
+
+    #[external(v0)]
+    fn __execute__(ref self: ContractState, calls: Array<Call>) -> Array<Span<felt252>> {
+        if get_caller_address().is_zero() {
+            self.nonce.write(self.nonce.read() + 1);
+        }
+        execute_calls(calls)
+    }
+
+    fn execute_calls(calls: Array<Call>) -> Array<Span<felt252>> {
+        let mut res = ArrayTrait::new();
+        let call = calls.at(0);
+        let result = call_contract_syscall(*call.to, *call.selector, *call.calldata)
+            .unwrap();
+        res.append(result);
+        res
+    }
+}
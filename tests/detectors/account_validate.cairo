@@ -0,0 +1,144 @@
+#[starknet::interface]
+trait IOracle<TContractState> {
+    fn is_allowed(self: @TContractState, account: starknet::ContractAddress) -> bool;
+}
+
+#[starknet::contract(account)]
+mod BadAccount {
+    use starknet::account::Call;
+    use starknet::{
+        ContractAddress, get_block_timestamp, get_caller_address, get_contract_address, get_tx_info
+    };
+    use core::zeroable::Zeroable;
+    use super::{IOracleDispatcher, IOracleDispatcherTrait};
+
+    #[storage]
+    struct Storage {
+        public_key: felt252,
+        oracle: ContractAddress,
+        expiry: u64,
+    }
+
+    #[external(v0)]
+    fn __validate__(self: @ContractState, calls: Array<Call>) -> felt252 {
+        assert(get_block_timestamp() < self.expiry.read(), 'Expired');
+        validate_signature(self);
+        starknet::VALIDATED
+    }
+
+    #[external(v0)]
+    fn __validate_declare__(self: @ContractState, class_hash: felt252) -> felt252 {
+        let allowed = IOracleDispatcher { contract_address: self.oracle.read() }
+            .is_allowed(get_contract_address());
+        assert(allowed, 'Not allowed');
+        validate_signature(self);
+        starknet::VALIDATED
+    }
+
+    #[external(v0)]
+    fn __execute__(ref self: ContractState, calls: Array<Call>) -> Array<Span<felt252>> {
+        assert(get_caller_address().is_zero(), 'Invalid caller');
+        ArrayTrait::new()
+    }
+
+    fn validate_signature(self: @ContractState) {
+        let tx_info = get_tx_info().unbox();
+        let signature = tx_info.signature;
+        assert(signature.len() == 2, 'Invalid signature length');
+        assert(
+            ecdsa::check_ecdsa_signature(
+                tx_info.transaction_hash, self.public_key.read(), *signature[0], *signature[1]
+            ),
+            'Invalid signature'
+        );
+    }
+}
+
+#[starknet::contract(account)]
+mod BadBlockInfoAccount {
+    use starknet::account::Call;
+    use starknet::{get_block_info, get_caller_address, get_execution_info, get_tx_info};
+    use core::zeroable::Zeroable;
+
+    #[storage]
+    struct Storage {
+        public_key: felt252,
+        expiry: u64,
+        last_block: u64,
+    }
+
+    #[external(v0)]
+    fn __validate__(self: @ContractState, calls: Array<Call>) -> felt252 {
+        let block_timestamp = get_execution_info().unbox().block_info.unbox().block_timestamp;
+        assert(block_timestamp < self.expiry.read(), 'Expired');
+        validate_signature(self);
+        starknet::VALIDATED
+    }
+
+    #[external(v0)]
+    fn __validate_declare__(self: @ContractState, class_hash: felt252) -> felt252 {
+        assert(get_block_info().unbox().block_number > self.last_block.read(), 'Too early');
+        validate_signature(self);
+        starknet::VALIDATED
+    }
+
+    #[external(v0)]
+    fn __execute__(ref self: ContractState, calls: Array<Call>) -> Array<Span<felt252>> {
+        assert(get_caller_address().is_zero(), 'Invalid caller');
+        ArrayTrait::new()
+    }
+
+    fn validate_signature(self: @ContractState) {
+        let tx_info = get_tx_info().unbox();
+        let signature = tx_info.signature;
+        assert(signature.len() == 2, 'Invalid signature length');
+        assert(
+            ecdsa::check_ecdsa_signature(
+                tx_info.transaction_hash, self.public_key.read(), *signature[0], *signature[1]
+            ),
+            'Invalid signature'
+        );
+    }
+}
+
+#[starknet::contract(account)]
+mod GoodAccount {
+    use starknet::account::Call;
+    use starknet::{get_caller_address, get_tx_info};
+    use core::zeroable::Zeroable;
+
+    #[storage]
+    struct Storage {
+        public_key: felt252,
+    }
+
+    #[external(v0)]
+    fn __validate__(self: @ContractState, calls: Array<Call>) -> felt252 {
+        validate_signature(self);
+        starknet::VALIDATED
+    }
+
+    #[external(v0)]
+    fn __validate_declare__(self: @ContractState, class_hash: felt252) -> felt252 {
+        validate_signature(self);
+        starknet::VALIDATED
+    }
+
+    #[external(v0)]
+    fn __execute__(ref self: ContractState, calls: Array<Call>) -> Array<Span<felt252>> {
+        assert(get_caller_address().is_zero(), 'Invalid caller');
+        ArrayTrait::new()
+    }
+
+    fn validate_signature(self: @ContractState) {
+        let tx_info = get_tx_info().unbox();
+        let signature = tx_info.signature;
+        assert(signature.len() == 2, 'Invalid signature length');
+        assert(
+            ecdsa::check_ecdsa_signature(
+                tx_info.transaction_hash, self.public_key.read(), *signature[0], *signature[1]
+            ),
+            'Invalid signature'
+        );
+    }
+}
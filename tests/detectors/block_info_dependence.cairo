@@ -0,0 +1,54 @@
+#[starknet::contract]
+mod BlockInfoDependence {
+    use starknet::{get_block_timestamp, get_block_info};
+    use starknet::info::get_block_number;
+
+    #[storage]
+    struct Storage {
+        deadline: u64,
+        seed: felt252,
+    }
+
+    #[external(v0)]
+    fn bad_modulo(self: @ContractState, players: u64) -> u64 {
+        get_block_timestamp() % players
+    }
+
+    #[external(v0)]
+    fn bad_hash(self: @ContractState) -> u256 {
+        let number = get_block_info().unbox().block_number;
+        let random: u256 = core::pedersen::pedersen(number.into(), 1).into();
+        random % 10
+    }
+
+    #[external(v0)]
+    fn good_hash_stored(ref self: ContractState) {
+        let number = get_block_info().unbox().block_number;
+        self.seed.write(core::pedersen::pedersen(number.into(), 1));
+    }
+
+    #[external(v0)]
+    fn bad_equality(self: @ContractState) -> bool {
+        get_block_timestamp() == self.deadline.read()
+    }
+
+    #[external(v0)]
+    fn bad_indirect(self: @ContractState) -> bool {
+        is_lucky_block(get_block_number())
+    }
+
+    #[external(v0)]
+    fn good_deadline(self: @ContractState) {
+        assert(get_block_timestamp() <= self.deadline.read(), 'Expired');
+    }
+
+    #[external(v0)]
+    fn good_day(self: @ContractState) -> u64 {
+        get_block_timestamp() / 86400
+    }
+
+    fn is_lucky_block(number: u64) -> bool {
+        let number: felt252 = number.into();
+        number == 100
+    }
+}
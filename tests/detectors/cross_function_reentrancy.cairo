@@ -0,0 +1,68 @@
+#[starknet::interface]
+trait IAnotherContract<T> {
+    fn foo(self: @T, a: u256);
+}
+
+#[starknet::contract]
+mod CrossFunctionReentrancy {
+    use super::IAnotherContractDispatcherTrait;
+    use super::IAnotherContractDispatcher;
+    use starknet::{ContractAddress, get_caller_address};
+
+    #[storage]
+    struct Storage {
+        balances: LegacyMap<ContractAddress, u256>,
+        total: u256,
+        pending: u256,
+    }
+
+    #[external(v0)]
+    fn bad_withdraw(ref self: ContractState, amount: u256) {
+        let caller = get_caller_address();
+        IAnotherContractDispatcher { contract_address: caller }.foo(amount);
+        self.balances.write(caller, 0);
+    }
+
+    #[external(v0)]
+    fn transfer(ref self: ContractState, to: ContractAddress) {
+        let caller = get_caller_address();
+        let balance = self.balances.read(caller);
+        self.balances.write(caller, 0);
+        add_balance(ref self, to, balance);
+    }
+
+    #[external(v0)]
+    fn bad_loop_withdraw(ref self: ContractState, amount: u256) {
+        let caller = get_caller_address();
+        let mut i: u8 = 0;
+        loop {
+            if i == 2 {
+                break;
+            }
+            IAnotherContractDispatcher { contract_address: caller }.foo(amount);
+            i += 1;
+        };
+        self.pending.write(0);
+    }
+
+    #[external(v0)]
+    fn is_empty(ref self: ContractState) {
+        assert(self.pending.read() == 0, 'Not empty');
+    }
+
+    #[external(v0)]
+    fn touch(ref self: ContractState) {
+        let _balance = self.balances.read(get_caller_address());
+    }
+
+    #[external(v0)]
+    fn good_withdraw(ref self: ContractState, amount: u256) {
+        let caller = get_caller_address();
+        self.total.write(self.total.read() - amount);
+        IAnotherContractDispatcher { contract_address: caller }.foo(amount);
+    }
+
+    fn add_balance(ref self: ContractState, to: ContractAddress, amount: u256) {
+        self.balances.write(to, self.balances.read(to) + amount);
+    }
+}
@@ -54,6 +54,25 @@ mod Felt252Overflow {
             p - 5
         }
     }
+    #[external(v0)]
+    fn safe_add_bounded(ref self:ContractState, user_param:u8, user_param2: u16) {
+        let a: felt252 = user_param.into();
+        let b: felt252 = user_param2.into();
+        self.b.write(a + b);
+    }
+
+    #[external(v0)]
+    fn safe_mul_bounded(ref self:ContractState, user_param:u64) {
+        let a: felt252 = user_param.into();
+        self.b.write(a * a);
+    }
+
+    #[external(v0)]
+    fn bad_sub_bounded(ref self:ContractState, user_param:u8) {
+        let a: felt252 = user_param.into();
+        self.b.write(a - 1);
+    }
+
     fn test_assert(p: felt252) {
         assert(4 != 0,'bad');
         assert(p == 3, 'ok');
@@ -0,0 +1,60 @@
+#[starknet::contract]
+mod IncorrectU256Handling {
+    #[storage]
+    struct Storage {
+        balance: u256,
+        small: u128,
+    }
+
+    #[external(v0)]
+    fn bad_param_low(ref self: ContractState, amount: u256) {
+        self.small.write(amount.low);
+    }
+
+    #[external(v0)]
+    fn bad_unwrap_or(ref self: ContractState, low: felt252, high: felt252) {
+        let value = u256 { low: low.try_into().unwrap_or(0), high: high.try_into().unwrap_or(0) };
+        self.balance.write(value);
+    }
+
+    #[external(v0)]
+    fn bad_match_default(ref self: ContractState, low: felt252) {
+        let low: u128 = match low.try_into() {
+            Option::Some(v) => v,
+            Option::None => 0,
+        };
+        self.balance.write(u256 { low, high: 0 });
+    }
+
+    #[external(v0)]
+    fn good_param_low(ref self: ContractState, amount: u256) {
+        assert(amount.high == 0, 'Too big');
+        self.small.write(amount.low);
+    }
+
+    #[external(v0)]
+    fn good_storage_low(ref self: ContractState) {
+        let balance = self.balance.read();
+        self.small.write(balance.low);
+    }
+
+    #[external(v0)]
+    fn good_unwrap(ref self: ContractState, low: felt252, high: felt252) {
+        let value = u256 { low: low.try_into().unwrap(), high: high.try_into().unwrap() };
+        self.balance.write(value);
+    }
+
+    #[external(v0)]
+    fn good_match_return(ref self: ContractState, low: felt252) {
+        let low: u128 = match low.try_into() {
+            Option::Some(v) => v,
+            Option::None => { return; },
+        };
+        self.balance.write(u256 { low, high: 0 });
+    }
+
+    #[external(v0)]
+    fn good_into(ref self: ContractState, value: felt252) {
+        self.balance.write(value.into());
+    }
+}
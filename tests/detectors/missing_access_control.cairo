@@ -0,0 +1,113 @@
+#[starknet::interface]
+trait IAnotherContract<T> {
+    fn foo(ref self: T, a: u128) -> u128;
+}
+
+#[starknet::contract]
+mod MissingAccessControl {
+    use super::IAnotherContractDispatcherTrait;
+    use super::IAnotherContractDispatcher;
+    use starknet::{ContractAddress, ClassHash, get_caller_address};
+    use starknet::syscalls::replace_class_syscall;
+
+    #[storage]
+    struct Storage {
+        owner: ContractAddress,
+        value: felt252,
+    }
+
+    #[constructor]
+    fn constructor(ref self: ContractState, owner: ContractAddress) {
+        self.owner.write(owner);
+    }
+
+    #[external(v0)]
+    fn bad_set_owner(ref self: ContractState, new_owner: ContractAddress) {
+        self.owner.write(new_owner);
+    }
+
+    #[external(v0)]
+    fn bad_upgrade(ref self: ContractState, class_hash: ClassHash) {
+        replace_class_syscall(class_hash).unwrap();
+    }
+
+    #[external(v0)]
+    fn bad_set_owner_indirect(ref self: ContractState, new_owner: ContractAddress) {
+        if new_owner.into() != 0 {
+            set_owner(ref self, new_owner);
+        }
+    }
+
+    #[external(v0)]
+    fn bad_set_owner_check_after(ref self: ContractState, new_owner: ContractAddress) {
+        self.owner.write(new_owner);
+        assert(get_caller_address() == self.owner.read(), 'Not owner');
+    }
+
+    #[external(v0)]
+    fn bad_set_owner_check_not_reverting(ref self: ContractState, new_owner: ContractAddress) {
+        if get_caller_address() == self.owner.read() {
+            self.value.write(0);
+        }
+        self.owner.write(new_owner);
+    }
+
+    #[external(v0)]
+    fn bad_set_value(ref self: ContractState, value: felt252) {
+        self.value.write(value);
+    }
+
+    #[external(v0)]
+    fn bad_external_call(ref self: ContractState, contract: ContractAddress) -> u128 {
+        IAnotherContractDispatcher { contract_address: contract }.foo(2_u128)
+    }
+
+    #[external(v0)]
+    fn good_set_owner(ref self: ContractState, new_owner: ContractAddress) {
+        assert(get_caller_address() == self.owner.read(), 'Not owner');
+        self.owner.write(new_owner);
+    }
+
+    #[external(v0)]
+    fn good_set_owner_if(ref self: ContractState, new_owner: ContractAddress) {
+        if get_caller_address() == self.owner.read() {
+            self.owner.write(new_owner);
+        }
+    }
+
+    #[external(v0)]
+    fn good_set_owner_helper(ref self: ContractState, new_owner: ContractAddress) {
+        assert_only_owner(@self);
+        self.owner.write(new_owner);
+    }
+
+    #[external(v0)]
+    fn good_set_owner_helper_param(ref self: ContractState, new_owner: ContractAddress) {
+        let caller = get_caller_address();
+        assert_is_owner(@self, caller);
+        self.owner.write(new_owner);
+    }
+
+    #[external(v0)]
+    fn good_upgrade(ref self: ContractState, class_hash: ClassHash) {
+        assert_only_owner(@self);
+        replace_class_syscall(class_hash).unwrap();
+    }
+
+    #[external(v0)]
+    fn get_value(self: @ContractState) -> felt252 {
+        self.value.read()
+    }
+
+    fn set_owner(ref self: ContractState, new_owner: ContractAddress) {
+        self.owner.write(new_owner);
+    }
+
+    fn assert_only_owner(self: @ContractState) {
+        assert(get_caller_address() == self.owner.read(), 'Not owner');
+    }
+
+    fn assert_is_owner(self: @ContractState, caller: ContractAddress) {
+        assert(caller == self.owner.read(), 'Not owner');
+    }
+}
@@ -0,0 +1,85 @@
+#[starknet::contract]
+mod MissingEvents {
+    use starknet::{ContractAddress, get_caller_address};
+
+    #[storage]
+    struct Storage {
+        owner: ContractAddress,
+        fee: u256,
+        paused: bool,
+    }
+
+    #[event]
+    #[derive(Drop, starknet::Event)]
+    enum Event {
+        FeeUpdated: FeeUpdated,
+    }
+
+    #[derive(Drop, starknet::Event)]
+    struct FeeUpdated {
+        fee: u256,
+    }
+
+    #[constructor]
+    fn constructor(ref self: ContractState, owner: ContractAddress) {
+        self.owner.write(owner);
+    }
+
+    #[external(v0)]
+    fn bad_set_owner(ref self: ContractState, owner: ContractAddress) {
+        assert(get_caller_address() == self.owner.read(), 'Not owner');
+        self.owner.write(owner);
+    }
+
+    #[external(v0)]
+    fn bad_pause(ref self: ContractState) {
+        only_owner(@self);
+        set_paused(ref self, true);
+    }
+
+    #[external(v0)]
+    fn good_set_fee(ref self: ContractState, fee: u256) {
+        assert(get_caller_address() == self.owner.read(), 'Not owner');
+        self.fee.write(fee);
+        self.emit(FeeUpdated { fee });
+    }
+
+    #[external(v0)]
+    fn bad_event_before_write(ref self: ContractState, fee: u256) {
+        assert(get_caller_address() == self.owner.read(), 'Not owner');
+        self.emit(FeeUpdated { fee });
+        self.fee.write(fee);
+    }
+
+    #[external(v0)]
+    fn bad_event_for_one_write(ref self: ContractState, fee: u256) {
+        assert(get_caller_address() == self.owner.read(), 'Not owner');
+        self.paused.write(true);
+        self.emit(FeeUpdated { fee });
+        self.fee.write(fee);
+    }
+
+    #[external(v0)]
+    fn good_event_in_private(ref self: ContractState, fee: u256) {
+        assert(get_caller_address() == self.owner.read(), 'Not owner');
+        self.fee.write(fee);
+        emit_fee_updated(ref self, fee);
+    }
+
+    #[external(v0)]
+    fn good_unprotected(ref self: ContractState) {
+        self.paused.write(false);
+    }
+
+    fn only_owner(self: @ContractState) {
+        assert(get_caller_address() == self.owner.read(), 'Not owner');
+    }
+
+    fn set_paused(ref self: ContractState, paused: bool) {
+        self.paused.write(paused);
+    }
+
+    fn emit_fee_updated(ref self: ContractState, fee: u256) {
+        self.emit(FeeUpdated { fee });
+    }
+}
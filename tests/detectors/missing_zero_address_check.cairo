@@ -0,0 +1,83 @@
+#[starknet::contract]
+mod MissingZeroAddressCheck {
+    use core::zeroable::Zeroable;
+    use starknet::ContractAddress;
+
+    #[storage]
+    struct Storage {
+        owner: ContractAddress,
+        treasury: ContractAddress,
+        token: ContractAddress,
+    }
+
+    #[constructor]
+    fn constructor(ref self: ContractState, owner: ContractAddress) {
+        self.owner.write(owner);
+    }
+
+    #[external(v0)]
+    fn bad_private(ref self: ContractState, owner: ContractAddress, treasury: ContractAddress) {
+        assert(!owner.is_zero(), 'zero address');
+        self.owner.write(owner);
+        set_treasury(ref self, treasury);
+    }
+
+    #[external(v0)]
+    fn bad_check_after(ref self: ContractState, token: ContractAddress) {
+        self.token.write(token);
+        assert(!token.is_zero(), 'zero address');
+    }
+
+    #[external(v0)]
+    fn good(ref self: ContractState, owner: ContractAddress) {
+        assert(!owner.is_zero(), 'zero address');
+        self.owner.write(owner);
+    }
+
+    #[external(v0)]
+    fn bad_eq_owner(ref self: ContractState, treasury: ContractAddress) {
+        assert(treasury != self.owner.read(), 'owner address');
+        set_treasury(ref self, treasury);
+    }
+
+    #[external(v0)]
+    fn bad_zero_branch(ref self: ContractState, owner: ContractAddress) {
+        if owner.is_zero() {
+            self.owner.write(owner);
+        }
+    }
+
+    #[external(v0)]
+    fn bad_other_check(ref self: ContractState, token: ContractAddress, enabled: bool) {
+        assert(enabled, 'disabled');
+        self.token.write(token);
+    }
+
+    #[external(v0)]
+    fn good_felt(ref self: ContractState, treasury: ContractAddress) {
+        let treasury_felt: felt252 = treasury.into();
+        assert(treasury_felt != 0, 'zero address');
+        set_treasury(ref self, treasury);
+    }
+
+    #[external(v0)]
+    fn good_non_zero_branch(ref self: ContractState, owner: ContractAddress) {
+        if owner.is_non_zero() {
+            self.owner.write(owner);
+        }
+    }
+
+    #[external(v0)]
+    fn good_private(ref self: ContractState, token: ContractAddress) {
+        set_token(ref self, token);
+    }
+
+    fn set_treasury(ref self: ContractState, treasury: ContractAddress) {
+        self.treasury.write(treasury);
+    }
+
+    fn set_token(ref self: ContractState, token: ContractAddress) {
+        assert(!token.is_zero(), 'zero address');
+        self.token.write(token);
+    }
+}
@@ -0,0 +1,38 @@
+#[starknet::interface]
+trait IAnotherContract<T> {
+    fn foo(self: @T, a: felt252);
+}
+
+#[starknet::contract]
+mod ReentrancyLibraryCall {
+    use super::IAnotherContractDispatcherTrait;
+    use super::IAnotherContractLibraryDispatcher;
+    use starknet::{ClassHash, ContractAddress, SyscallResultTrait};
+    use starknet::syscalls::call_contract_syscall;
+
+    #[storage]
+    struct Storage {
+        a: felt252,
+    }
+
+    #[external(v0)]
+    fn bad_library_call(ref self: ContractState, class_hash: ClassHash) {
+        let a = self.a.read();
+        IAnotherContractLibraryDispatcher { class_hash }.foo(a);
+        self.a.write(4);
+    }
+
+    #[external(v0)]
+    fn bad_call_contract_syscall(ref self: ContractState, address: ContractAddress) {
+        let a = self.a.read();
+        call_contract_syscall(address, selector!("foo"), array![a].span()).unwrap_syscall();
+        self.a.write(4);
+    }
+
+    #[external(v0)]
+    fn good_library_call(ref self: ContractState, class_hash: ClassHash) {
+        let a = self.a.read();
+        self.a.write(4);
+        IAnotherContractLibraryDispatcher { class_hash }.foo(a);
+    }
+}
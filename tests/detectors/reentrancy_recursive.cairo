@@ -0,0 +1,76 @@
+#[starknet::interface]
+trait IAnotherContract<T> {
+    fn foo(self: @T, a: felt252);
+}
+
+#[starknet::contract]
+mod TestContract {
+    use super::IAnotherContractDispatcherTrait;
+    use super::IAnotherContractDispatcher;
+    use starknet::ContractAddress;
+
+    #[storage]
+    struct Storage {
+        a: felt252,
+        b: felt252,
+    }
+
+    #[external(v0)]
+    fn good_recursive(ref self: ContractState, address: ContractAddress, n: u32) {
+        let a = self.a.read();
+        write_recursive(ref self, n);
+        IAnotherContractDispatcher { contract_address: address }.foo(a);
+    }
+
+    #[external(v0)]
+    fn bad_recursive_call(ref self: ContractState, address: ContractAddress, n: u32) {
+        let a = self.a.read();
+        call_recursive(address, a, n);
+        self.a.write(4);
+    }
+
+    #[external(v0)]
+    fn bad_recursive_write(ref self: ContractState, address: ContractAddress, n: u32) {
+        let b = self.b.read();
+        IAnotherContractDispatcher { contract_address: address }.foo(b);
+        write_recursive(ref self, n);
+    }
+
+    #[external(v0)]
+    fn bad_mutually_recursive(ref self: ContractState, address: ContractAddress, n: u32) {
+        let a = self.a.read();
+        ping(ref self, address, a, n);
+    }
+
+    // The external call is done only after the first recursive call
+    fn call_recursive(address: ContractAddress, a: felt252, n: u32) {
+        if n == 0 {
+            return;
+        }
+        call_recursive(address, a, n - 1);
+        if n == 1 {
+            IAnotherContractDispatcher { contract_address: address }.foo(a);
+        }
+    }
+
+    fn write_recursive(ref self: ContractState, n: u32) {
+        if n == 0 {
+            return;
+        }
+        self.b.write(n.into());
+        write_recursive(ref self, n - 1);
+    }
+
+    fn ping(ref self: ContractState, address: ContractAddress, a: felt252, n: u32) {
+        if n == 0 {
+            self.a.write(a);
+            return;
+        }
+        pong(ref self, address, a, n - 1);
+    }
+
+    fn pong(ref self: ContractState, address: ContractAddress, a: felt252, n: u32) {
+        IAnotherContractDispatcher { contract_address: address }.foo(a);
+        ping(ref self, address, a, n);
+    }
+}
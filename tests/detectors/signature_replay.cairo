@@ -0,0 +1,54 @@
+#[starknet::contract]
+mod SignatureReplay {
+    use core::ecdsa::check_ecdsa_signature;
+    use core::pedersen::pedersen;
+    use starknet::get_tx_info;
+
+    #[storage]
+    struct Storage {
+        public_key: felt252,
+        nonce: felt252,
+        balance: felt252,
+    }
+
+    #[external(v0)]
+    fn bad_no_nonce(ref self: ContractState, amount: felt252, r: felt252, s: felt252) {
+        let hash = pedersen(amount, 0);
+        assert(check_ecdsa_signature(hash, self.public_key.read(), r, s), 'Invalid signature');
+        self.balance.write(amount);
+    }
+
+    #[external(v0)]
+    fn bad_nonce_not_written(ref self: ContractState, amount: felt252, r: felt252, s: felt252) {
+        let nonce = self.nonce.read();
+        let hash = pedersen(amount, nonce);
+        assert(check_ecdsa_signature(hash, self.public_key.read(), r, s), 'Invalid signature');
+        self.balance.write(amount);
+    }
+
+    #[external(v0)]
+    fn good_nonce(ref self: ContractState, amount: felt252, r: felt252, s: felt252) {
+        let nonce = self.nonce.read();
+        let hash = pedersen(amount, nonce);
+        assert(check_ecdsa_signature(hash, self.public_key.read(), r, s), 'Invalid signature');
+        self.nonce.write(nonce + 1);
+        self.balance.write(amount);
+    }
+
+    #[external(v0)]
+    fn good_chain_id(ref self: ContractState, amount: felt252, r: felt252, s: felt252) {
+        let tx_info = get_tx_info().unbox();
+        let hash = pedersen(amount, tx_info.chain_id);
+        assert(check_ecdsa_signature(hash, self.public_key.read(), r, s), 'Invalid signature');
+        self.balance.write(amount);
+    }
+
+    #[external(v0)]
+    fn good_tx_hash(ref self: ContractState, r: felt252, s: felt252) {
+        let tx_info = get_tx_info().unbox();
+        assert(
+            check_ecdsa_signature(tx_info.transaction_hash, self.public_key.read(), r, s),
+            'Invalid signature'
+        );
+    }
+}
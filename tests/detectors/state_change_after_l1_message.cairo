@@ -0,0 +1,38 @@
+#[starknet::contract]
+mod StateChangeAfterL1Message {
+    use starknet::{EthAddress, SyscallResultTrait};
+    use starknet::syscalls::send_message_to_l1_syscall;
+
+    #[storage]
+    struct Storage {
+        l1_bridge: felt252,
+        balance: u256,
+        withdrawals: u128,
+    }
+
+    #[external(v0)]
+    fn bad(ref self: ContractState, l1_recipient: EthAddress, amount: u256) {
+        let payload = array![l1_recipient.into(), amount.low.into(), amount.high.into()];
+        send_message_to_l1_syscall(self.l1_bridge.read(), payload.span()).unwrap_syscall();
+        self.balance.write(self.balance.read() - amount);
+    }
+
+    #[external(v0)]
+    fn bad_indirect(ref self: ContractState, l1_recipient: EthAddress, amount: u256) {
+        let payload = array![l1_recipient.into(), amount.low.into(), amount.high.into()];
+        send_message_to_l1_syscall(self.l1_bridge.read(), payload.span()).unwrap_syscall();
+        increment_withdrawals(ref self);
+    }
+
+    #[external(v0)]
+    fn good(ref self: ContractState, l1_recipient: EthAddress, amount: u256) {
+        self.balance.write(self.balance.read() - amount);
+        increment_withdrawals(ref self);
+        let payload = array![l1_recipient.into(), amount.low.into(), amount.high.into()];
+        send_message_to_l1_syscall(self.l1_bridge.read(), payload.span()).unwrap_syscall();
+    }
+
+    fn increment_withdrawals(ref self: ContractState) {
+        self.withdrawals.write(self.withdrawals.read() + 1);
+    }
+}
@@ -0,0 +1,76 @@
+#[starknet::component]
+mod OwnableComponent {
+    use starknet::ContractAddress;
+
+    #[storage]
+    struct Storage {
+        owner: ContractAddress,
+        balances: LegacyMap<ContractAddress, u256>,
+    }
+
+    #[generate_trait]
+    impl InternalImpl<
+        TContractState, +HasComponent<TContractState>
+    > of InternalTrait<TContractState> {
+        fn set_owner(ref self: ComponentState<TContractState>, owner: ContractAddress) {
+            self.owner.write(owner);
+            self.balances.write(owner, 1);
+        }
+    }
+}
+
+#[starknet::component]
+mod CounterComponent {
+    #[storage]
+    struct Storage {
+        counter: u128,
+    }
+
+    #[generate_trait]
+    impl InternalImpl<
+        TContractState, +HasComponent<TContractState>
+    > of InternalTrait<TContractState> {
+        fn increment(ref self: ComponentState<TContractState>) {
+            self.counter.write(self.counter.read() + 1);
+        }
+    }
+}
+
+#[starknet::contract]
+mod StorageCollision {
+    use starknet::ContractAddress;
+    use super::{OwnableComponent, CounterComponent};
+
+    component!(path: OwnableComponent, storage: ownable, event: OwnableEvent);
+    component!(path: CounterComponent, storage: counter, event: CounterEvent);
+
+    impl OwnableInternalImpl = OwnableComponent::InternalImpl<ContractState>;
+    impl CounterInternalImpl = CounterComponent::InternalImpl<ContractState>;
+
+    #[storage]
+    struct Storage {
+        #[substorage(v0)]
+        ownable: OwnableComponent::Storage,
+        #[substorage(v0)]
+        counter: CounterComponent::Storage,
+        owner: ContractAddress,
+        balances: LegacyMap<ContractAddress, u256>,
+        value: felt252,
+    }
+
+    #[event]
+    #[derive(Drop, starknet::Event)]
+    enum Event {
+        OwnableEvent: OwnableComponent::Event,
+        CounterEvent: CounterComponent::Event,
+    }
+
+    #[external(v0)]
+    fn set(ref self: ContractState, owner: ContractAddress) {
+        self.ownable.set_owner(owner);
+        self.counter.increment();
+        self.owner.write(owner);
+        self.balances.write(owner, 2);
+        self.value.write(1);
+    }
+}
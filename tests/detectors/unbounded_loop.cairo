@@ -0,0 +1,103 @@
+#[starknet::contract]
+mod UnboundedLoop {
+    use starknet::{ContractAddress, get_caller_address};
+
+    #[storage]
+    struct Storage {
+        owner: ContractAddress,
+        users_len: u32,
+        admins_len: u32,
+        total: u128,
+    }
+
+    #[external(v0)]
+    fn bad_loop_span(ref self: ContractState, mut values: Span<u128>) {
+        let mut total = 0;
+        loop {
+            match values.pop_front() {
+                Option::Some(value) => { total += *value; },
+                Option::None => { break; },
+            }
+        };
+        self.total.write(total);
+    }
+
+    #[external(v0)]
+    fn bad_loop_counter(ref self: ContractState, n: u32) {
+        let mut i = 0;
+        loop {
+            if i == n {
+                break;
+            }
+            i += 1;
+        };
+        self.users_len.write(i);
+    }
+
+    #[external(v0)]
+    fn bad_loop_indirect(ref self: ContractState, n: u32) {
+        if n != 0 {
+            count_to(ref self, n);
+        }
+    }
+
+    #[external(v0)]
+    fn bad_loop_storage(ref self: ContractState) {
+        let len = self.users_len.read();
+        let mut i = 0;
+        loop {
+            if i == len {
+                break;
+            }
+            i += 1;
+        };
+        self.total.write(i.into());
+    }
+
+    #[external(v0)]
+    fn add_user(ref self: ContractState) {
+        self.users_len.write(self.users_len.read() + 1);
+    }
+
+    #[external(v0)]
+    fn add_admin(ref self: ContractState) {
+        assert(get_caller_address() == self.owner.read(), 'Not owner');
+        self.admins_len.write(self.admins_len.read() + 1);
+    }
+
+    #[external(v0)]
+    fn good_loop_admins(ref self: ContractState) {
+        let len = self.admins_len.read();
+        let mut i = 0;
+        loop {
+            if i == len {
+                break;
+            }
+            i += 1;
+        };
+        self.total.write(i.into());
+    }
+
+    #[external(v0)]
+    fn good_loop_constant(ref self: ContractState) {
+        let mut i: u32 = 0;
+        loop {
+            if i == 10 {
+                break;
+            }
+            i += 1;
+        };
+        self.users_len.write(i);
+    }
+
+    fn count_to(ref self: ContractState, n: u32) {
+        let mut i = 0;
+        loop {
+            if i == n {
+                break;
+            }
+            i += 1;
+        };
+        self.total.write(i.into());
+    }
+}
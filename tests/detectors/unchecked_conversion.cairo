@@ -0,0 +1,34 @@
+#[starknet::contract]
+mod UncheckedConversion {
+    #[storage]
+    struct Storage {
+        value: u128,
+        small: u64,
+    }
+
+    #[external(v0)]
+    fn bad_felt252_to_u128(ref self: ContractState, value: felt252) {
+        let v: u128 = value.try_into().unwrap();
+        self.value.write(v);
+    }
+
+    #[external(v0)]
+    fn bad_downcast(ref self: ContractState, value: u128) {
+        let v: u64 = value.try_into().unwrap();
+        self.small.write(v);
+    }
+
+    #[external(v0)]
+    fn good_felt252_to_u128(ref self: ContractState, value: felt252) {
+        match value.try_into() {
+            Option::Some(v) => self.value.write(v),
+            Option::None => (),
+        }
+    }
+
+    #[external(v0)]
+    fn good_storage_downcast(ref self: ContractState) {
+        let v: u64 = self.value.read().try_into().unwrap();
+        self.small.write(v);
+    }
+}
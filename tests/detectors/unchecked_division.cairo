@@ -0,0 +1,69 @@
+#[starknet::contract]
+mod UncheckedDivision {
+    #[storage]
+    struct Storage {
+        value: u128,
+        value256: u256,
+        felt: felt252,
+    }
+
+    #[external(v0)]
+    fn bad_div(ref self: ContractState, divisor: u128) {
+        self.value.write(100 / divisor);
+    }
+
+    #[external(v0)]
+    fn bad_rem(ref self: ContractState, divisor: u64) {
+        self.value.write((100 % divisor).into());
+    }
+
+    #[external(v0)]
+    fn bad_div_u256(ref self: ContractState, divisor: u256) {
+        self.value256.write(100 / divisor);
+    }
+
+    #[external(v0)]
+    fn bad_felt252_div(ref self: ContractState, a: felt252, b: NonZero<felt252>) {
+        self.felt.write(felt252_div(a, b));
+    }
+
+    #[external(v0)]
+    fn bad_div_bound_check(ref self: ContractState, divisor: u128) {
+        if divisor > 1000 {
+            return;
+        }
+        self.value.write(100 / divisor);
+    }
+
+    #[external(v0)]
+    fn bad_div_zero_branch(ref self: ContractState, divisor: u128) {
+        if divisor == 0 {
+            self.value.write(100 / divisor);
+        }
+    }
+
+    #[external(v0)]
+    fn good_div(ref self: ContractState, divisor: u128) {
+        if divisor == 0 {
+            return;
+        }
+        self.value.write(100 / divisor);
+    }
+
+    #[external(v0)]
+    fn good_div_u256(ref self: ContractState, divisor: u256) {
+        assert(divisor != 0, 'Division by zero');
+        self.value256.write(100 / divisor);
+    }
+
+    #[external(v0)]
+    fn good_div_u256_struct(ref self: ContractState, divisor: u256) {
+        assert(divisor != u256 { low: 0, high: 0 }, 'Division by zero');
+        self.value256.write(100 / divisor);
+    }
+
+    #[external(v0)]
+    fn good_div_storage(ref self: ContractState, value: u128) {
+        self.value.write(value / self.value.read());
+    }
+}
@@ -0,0 +1,52 @@
+#[starknet::contract]
+mod UncheckedL1MessageAddress {
+    use starknet::SyscallResultTrait;
+    use starknet::syscalls::send_message_to_l1_syscall;
+
+    #[storage]
+    struct Storage {
+        l1_bridge: felt252,
+    }
+
+    #[external(v0)]
+    fn bad(ref self: ContractState, to_address: felt252, amount: u128) {
+        send_message_to_l1_syscall(to_address, array![amount.into()].span()).unwrap_syscall();
+    }
+
+    #[external(v0)]
+    fn bad_indirect(ref self: ContractState, to_address: felt252, amount: u128) {
+        send_message(to_address, amount);
+    }
+
+    #[external(v0)]
+    fn good_checked(ref self: ContractState, to_address: felt252, amount: u128) {
+        assert(to_address == self.l1_bridge.read(), 'Wrong L1 address');
+        send_message_to_l1_syscall(to_address, array![amount.into()].span()).unwrap_syscall();
+    }
+
+    #[external(v0)]
+    fn bad_check_not_reverting(ref self: ContractState, to_address: felt252, amount: u128) {
+        let mut fee = 0;
+        if to_address != self.l1_bridge.read() {
+            fee = 1;
+        }
+        send_message_to_l1_syscall(to_address, array![amount.into(), fee].span()).unwrap_syscall();
+    }
+
+    #[external(v0)]
+    fn good_checked_if(ref self: ContractState, to_address: felt252, amount: u128) {
+        if to_address == self.l1_bridge.read() {
+            send_message_to_l1_syscall(to_address, array![amount.into()].span()).unwrap_syscall();
+        }
+    }
+
+    #[external(v0)]
+    fn good_storage(ref self: ContractState, amount: u128) {
+        send_message_to_l1_syscall(self.l1_bridge.read(), array![amount.into()].span())
+            .unwrap_syscall();
+    }
+
+    fn send_message(to_address: felt252, amount: u128) {
+        send_message_to_l1_syscall(to_address, array![amount.into()].span()).unwrap_syscall();
+    }
+}
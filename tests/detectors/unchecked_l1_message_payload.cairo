@@ -0,0 +1,56 @@
+#[starknet::contract]
+mod UncheckedL1MessagePayload {
+    use starknet::{EthAddress, SyscallResultTrait};
+    use starknet::syscalls::send_message_to_l1_syscall;
+
+    #[storage]
+    struct Storage {
+        l1_bridge: felt252,
+    }
+
+    #[external(v0)]
+    fn bad(ref self: ContractState, l1_recipient: felt252, amount: u256) {
+        let mut payload = ArrayTrait::new();
+        payload.append(l1_recipient);
+        payload.append(amount.low.into());
+        payload.append(amount.high.into());
+        send_message_to_l1_syscall(self.l1_bridge.read(), payload.span()).unwrap_syscall();
+    }
+
+    #[external(v0)]
+    fn good_eth_address(ref self: ContractState, l1_recipient: EthAddress, amount: u256) {
+        let l1_recipient: felt252 = l1_recipient.into();
+        let payload = array![l1_recipient, amount.low.into(), amount.high.into()];
+        send_message_to_l1_syscall(self.l1_bridge.read(), payload.span()).unwrap_syscall();
+    }
+
+    #[external(v0)]
+    fn good_checked(ref self: ContractState, l1_recipient: felt252, amount: u256) {
+        let l1_recipient: EthAddress = l1_recipient.try_into().unwrap();
+        let payload = array![l1_recipient.into(), amount.low.into(), amount.high.into()];
+        send_message_to_l1_syscall(self.l1_bridge.read(), payload.span()).unwrap_syscall();
+    }
+
+    #[external(v0)]
+    fn good_bounded(ref self: ContractState, l1_recipient: felt252, amount: u256) {
+        let recipient: u256 = l1_recipient.into();
+        assert(recipient < 0x10000000000000000000000000000000000000000, 'Invalid L1 address');
+        let payload = array![l1_recipient, amount.low.into(), amount.high.into()];
+        send_message_to_l1_syscall(self.l1_bridge.read(), payload.span()).unwrap_syscall();
+    }
+
+    #[external(v0)]
+    fn bad_not_bounded(ref self: ContractState, l1_recipient: felt252, amount: u256) {
+        assert(l1_recipient != 0, 'Invalid L1 address');
+        let payload = array![l1_recipient, amount.low.into(), amount.high.into()];
+        send_message_to_l1_syscall(self.l1_bridge.read(), payload.span()).unwrap_syscall();
+    }
+
+    #[external(v0)]
+    fn bad_bound_too_large(ref self: ContractState, l1_recipient: felt252, amount: u256) {
+        let recipient: u256 = l1_recipient.into();
+        assert(recipient < 0x100000000000000000000000000000000000000000000, 'Invalid L1 address');
+        let payload = array![l1_recipient, amount.low.into(), amount.high.into()];
+        send_message_to_l1_syscall(self.l1_bridge.read(), payload.span()).unwrap_syscall();
+    }
+}
@@ -0,0 +1,58 @@
+#[starknet::contract]
+mod UncheckedSignature {
+    use core::ecdsa::check_ecdsa_signature;
+    use core::ec::ec_point_from_x_nz;
+    use starknet::get_tx_info;
+    use starknet::SyscallResultTrait;
+    use starknet::secp256k1::secp256k1_get_point_from_x_syscall;
+
+    #[storage]
+    struct Storage {
+        public_key: felt252,
+    }
+
+    #[external(v0)]
+    fn bad_unchecked(ref self: ContractState, r: felt252, s: felt252) {
+        let tx_info = get_tx_info().unbox();
+        check_ecdsa_signature(tx_info.transaction_hash, self.public_key.read(), r, s);
+    }
+
+    #[external(v0)]
+    fn bad_unchecked_let(ref self: ContractState, r: felt252, s: felt252) -> felt252 {
+        let tx_info = get_tx_info().unbox();
+        let _is_valid = check_ecdsa_signature(
+            tx_info.transaction_hash, self.public_key.read(), r, s
+        );
+        starknet::VALIDATED
+    }
+
+    #[external(v0)]
+    fn good_assert(ref self: ContractState, r: felt252, s: felt252) {
+        let tx_info = get_tx_info().unbox();
+        assert(
+            check_ecdsa_signature(tx_info.transaction_hash, self.public_key.read(), r, s),
+            'Invalid signature'
+        );
+    }
+
+    #[external(v0)]
+    fn good_return(self: @ContractState, hash: felt252, r: felt252, s: felt252) -> bool {
+        check_ecdsa_signature(hash, self.public_key.read(), r, s)
+    }
+
+    #[external(v0)]
+    fn bad_unchecked_point(self: @ContractState, r: u256) {
+        let _point = secp256k1_get_point_from_x_syscall(r, true).unwrap_syscall();
+    }
+
+    #[external(v0)]
+    fn bad_unchecked_ec_point(self: @ContractState, r: felt252) {
+        let _point = ec_point_from_x_nz(r);
+    }
+
+    #[external(v0)]
+    fn good_checked_point(ref self: ContractState, r: u256) {
+        let point = secp256k1_get_point_from_x_syscall(r, true).unwrap_syscall();
+        assert(point.is_some(), 'Invalid signature');
+    }
+}
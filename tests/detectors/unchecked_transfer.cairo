@@ -0,0 +1,63 @@
+use starknet::ContractAddress;
+
+#[starknet::interface]
+trait IERC20<T> {
+    fn transfer(ref self: T, recipient: ContractAddress, amount: u256) -> bool;
+    fn transfer_from(
+        ref self: T, sender: ContractAddress, recipient: ContractAddress, amount: u256
+    ) -> bool;
+    fn approve(ref self: T, spender: ContractAddress, amount: u256) -> bool;
+}
+
+#[starknet::contract]
+mod UncheckedTransfer {
+    use super::{IERC20Dispatcher, IERC20DispatcherTrait};
+    use starknet::{ContractAddress, get_caller_address, get_contract_address};
+
+    #[storage]
+    struct Storage {
+        token: IERC20Dispatcher,
+    }
+
+    #[external(v0)]
+    fn bad_ignored(ref self: ContractState, recipient: ContractAddress, amount: u256) {
+        self.token.read().transfer(recipient, amount);
+    }
+
+    #[external(v0)]
+    fn bad_captured(ref self: ContractState, amount: u256) {
+        let _success = self
+            .token
+            .read()
+            .transfer_from(get_caller_address(), get_contract_address(), amount);
+    }
+
+    #[external(v0)]
+    fn bad_approve(ref self: ContractState, spender: ContractAddress, amount: u256) {
+        let success = self.token.read().approve(spender, amount);
+        let _not_success = !success;
+    }
+
+    #[external(v0)]
+    fn good_assert(ref self: ContractState, recipient: ContractAddress, amount: u256) {
+        let success = self.token.read().transfer(recipient, amount);
+        assert(success, 'Transfer failed');
+    }
+
+    #[external(v0)]
+    fn good_if(ref self: ContractState, amount: u256) {
+        if !self.token.read().transfer_from(get_caller_address(), get_contract_address(), amount) {
+            panic_with_felt252('Transfer failed');
+        }
+    }
+
+    #[external(v0)]
+    fn good_eq(ref self: ContractState, spender: ContractAddress, amount: u256) {
+        assert(self.token.read().approve(spender, amount) == true, 'Approve failed');
+    }
+
+    #[external(v0)]
+    fn good_returned(ref self: ContractState, recipient: ContractAddress, amount: u256) -> bool {
+        self.token.read().transfer(recipient, amount)
+    }
+}
@@ -0,0 +1,97 @@
+#[starknet::interface]
+trait IAnotherContract<T> {
+    fn foo(ref self: T, a: u128) -> u128;
+    fn bar(self: @T, a: u128) -> u128;
+}
+
+#[starknet::interface]
+trait IExternalContract<T> {
+    fn get_value(self: @T) -> u128;
+}
+
+#[starknet::contract]
+mod AnotherContract {
+    #[storage]
+    struct Storage {
+        value: u128,
+    }
+
+    #[abi(embed_v0)]
+    impl AnotherContractImpl of super::IAnotherContract<ContractState> {
+        fn foo(ref self: ContractState, a: u128) -> u128 {
+            self.value.write(a);
+            a
+        }
+
+        fn bar(self: @ContractState, a: u128) -> u128 {
+            self.value.read() + a
+        }
+    }
+}
+
+#[starknet::contract]
+mod UnenforcedView {
+    use super::IAnotherContractDispatcherTrait;
+    use super::IAnotherContractDispatcher;
+    use super::IExternalContractDispatcherTrait;
+    use super::IExternalContractDispatcher;
+    use starknet::{ContractAddress, SyscallResultTrait};
+    use starknet::storage_access::storage_address_from_base_and_offset;
+    use starknet::syscalls::{storage_write_syscall, emit_event_syscall};
+
+    #[storage]
+    struct Storage {
+        value: felt252,
+    }
+
+    #[external(v0)]
+    fn bad_storage_write_syscall(self: @ContractState, value: felt252) {
+        let address = storage_address_from_base_and_offset(self.value.address(), 0);
+        storage_write_syscall(0, address, value).unwrap_syscall();
+    }
+
+    #[external(v0)]
+    fn bad_emit_event_syscall(self: @ContractState, value: felt252) {
+        emit_event_syscall(array![1].span(), array![value].span()).unwrap_syscall();
+    }
+
+    #[external(v0)]
+    fn bad_external_call(self: @ContractState, contract: ContractAddress) -> u128 {
+        IAnotherContractDispatcher { contract_address: contract }.foo(2_u128)
+    }
+
+    #[external(v0)]
+    fn bad_new_state(self: @ContractState, value: felt252) {
+        let mut state = unsafe_new_contract_state();
+        state.value.write(value);
+    }
+
+    #[external(v0)]
+    fn bad_indirect(self: @ContractState, value: felt252) {
+        if value != 0 {
+            write_value(self, value);
+        }
+    }
+
+    #[external(v0)]
+    fn good_view_call(self: @ContractState, contract: ContractAddress) -> u128 {
+        IAnotherContractDispatcher { contract_address: contract }.bar(2_u128)
+    }
+
+    #[external(v0)]
+    fn bad_unknown_external_call(self: @ContractState, contract: ContractAddress) -> u128 {
+        IExternalContractDispatcher { contract_address: contract }.get_value()
+    }
+
+    #[external(v0)]
+    fn good_view(self: @ContractState) -> felt252 {
+        self.value.read()
+    }
+
+    fn write_value(self: @ContractState, value: felt252) {
+        if value != 1 {
+            let address = storage_address_from_base_and_offset(self.value.address(), 0);
+            storage_write_syscall(0, address, value).unwrap_syscall();
+        }
+    }
+}
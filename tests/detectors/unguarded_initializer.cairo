@@ -0,0 +1,69 @@
+#[starknet::contract]
+mod UnguardedInitializer {
+    use starknet::ContractAddress;
+
+    #[storage]
+    struct Storage {
+        owner: ContractAddress,
+        token: ContractAddress,
+        fee: u128,
+        initialized: bool,
+    }
+
+    #[external(v0)]
+    fn initialize(ref self: ContractState, owner: ContractAddress, token: ContractAddress) {
+        self.owner.write(owner);
+        self.token.write(token);
+    }
+
+    #[external(v0)]
+    fn init_fee(ref self: ContractState, fee: u128) {
+        // The flag is checked but never set
+        assert(!self.initialized.read(), 'Already initialized');
+        assert(fee < 1000, 'Fee too high');
+        self.fee.write(fee);
+    }
+
+    #[external(v0)]
+    fn initializer(ref self: ContractState, owner: ContractAddress, token: ContractAddress) {
+        assert_not_initialized(@self);
+        self.initialized.write(true);
+        self.owner.write(owner);
+        self.token.write(token);
+    }
+
+    #[external(v0)]
+    fn init(ref self: ContractState, fee: u128) {
+        assert(!self.initialized.read(), 'Already initialized');
+        assert(fee < 1000, 'Fee too high');
+        self.initialized.write(true);
+        self.fee.write(fee);
+    }
+
+    #[external(v0)]
+    fn init_owner(ref self: ContractState, owner: ContractAddress) {
+        // The flag is checked without reverting
+        if self.fee.read() == 0 {
+            self.fee.write(1);
+        }
+        self.owner.write(owner);
+    }
+
+    #[external(v0)]
+    fn init_token(ref self: ContractState, token: ContractAddress) {
+        // The storage is written before checking the flag
+        self.token.write(token);
+        assert(!self.initialized.read(), 'Already initialized');
+        self.initialized.write(true);
+    }
+
+    #[external(v0)]
+    fn initiate_transfer(ref self: ContractState, token: ContractAddress) {
+        assert(self.owner.read() == starknet::get_caller_address(), 'Not owner');
+        self.token.write(token);
+    }
+
+    fn assert_not_initialized(self: @ContractState) {
+        assert(!self.initialized.read(), 'Already initialized');
+    }
+}
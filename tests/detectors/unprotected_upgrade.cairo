@@ -0,0 +1,77 @@
+#[starknet::contract]
+mod UnprotectedUpgrade {
+    use starknet::{ContractAddress, ClassHash, get_caller_address};
+    use starknet::syscalls::replace_class_syscall;
+
+    #[storage]
+    struct Storage {
+        owner: ContractAddress,
+    }
+
+    #[constructor]
+    fn constructor(ref self: ContractState, owner: ContractAddress) {
+        self.owner.write(owner);
+    }
+
+    #[external(v0)]
+    fn bad_upgrade(ref self: ContractState, class_hash: ClassHash) {
+        replace_class_syscall(class_hash).unwrap();
+    }
+
+    #[external(v0)]
+    fn bad_upgrade_check_after(ref self: ContractState, class_hash: ClassHash) {
+        replace_class_syscall(class_hash).unwrap();
+        assert_only_owner(@self);
+    }
+
+    #[external(v0)]
+    fn bad_upgrade_indirect(ref self: ContractState, class_hash: ClassHash, value: felt252) {
+        if value != 0 {
+            upgrade(class_hash, value);
+        }
+    }
+
+    #[external(v0)]
+    fn bad_upgrade_check_not_reverting(ref self: ContractState, class_hash: ClassHash) {
+        if get_caller_address() == self.owner.read() {
+            self.owner.write(get_caller_address());
+        }
+        replace_class_syscall(class_hash).unwrap();
+    }
+
+    #[external(v0)]
+    fn good_upgrade(ref self: ContractState, class_hash: ClassHash) {
+        assert(get_caller_address() == self.owner.read(), 'Not owner');
+        replace_class_syscall(class_hash).unwrap();
+    }
+
+    #[external(v0)]
+    fn good_upgrade_helper(ref self: ContractState, class_hash: ClassHash) {
+        assert_only_owner(@self);
+        replace_class_syscall(class_hash).unwrap();
+    }
+
+    #[external(v0)]
+    fn good_upgrade_indirect(ref self: ContractState, class_hash: ClassHash, value: felt252) {
+        assert_only_owner(@self);
+        if value != 0 {
+            protected_upgrade(class_hash, value);
+        }
+    }
+
+    fn upgrade(class_hash: ClassHash, value: felt252) {
+        if value != 1 {
+            replace_class_syscall(class_hash).unwrap();
+        }
+    }
+
+    fn protected_upgrade(class_hash: ClassHash, value: felt252) {
+        if value != 1 {
+            replace_class_syscall(class_hash).unwrap();
+        }
+    }
+
+    fn assert_only_owner(self: @ContractState) {
+        assert(get_caller_address() == self.owner.read(), 'Not owner');
+    }
+}
@@ -0,0 +1,45 @@
+#[starknet::contract]
+mod UnsafeDeploy {
+    use starknet::{ClassHash, ContractAddress, SyscallResultTrait, get_caller_address};
+    use starknet::syscalls::deploy_syscall;
+    use core::pedersen::pedersen;
+
+    #[storage]
+    struct Storage {
+        class_hash: ClassHash,
+        deployed: ContractAddress,
+    }
+
+    #[external(v0)]
+    fn bad_class_hash(ref self: ContractState, class_hash: ClassHash) {
+        let (address, _) = deploy_syscall(class_hash, 0, array![].span(), false).unwrap_syscall();
+        self.deployed.write(address);
+    }
+
+    #[external(v0)]
+    fn bad_salt(ref self: ContractState, salt: felt252) -> ContractAddress {
+        let (address, _) = deploy_syscall(self.class_hash.read(), salt, array![].span(), false)
+            .unwrap_syscall();
+        address
+    }
+
+    #[external(v0)]
+    fn bad_deploy_from_zero(ref self: ContractState) {
+        let (address, _) = deploy_syscall(self.class_hash.read(), 0, array![].span(), true)
+            .unwrap_syscall();
+        self.deployed.write(address);
+    }
+
+    #[external(v0)]
+    fn bad_ignored_address(ref self: ContractState) {
+        deploy_syscall(self.class_hash.read(), 0, array![].span(), false).unwrap_syscall();
+    }
+
+    #[external(v0)]
+    fn good(ref self: ContractState, salt: felt252) {
+        let salt = pedersen(get_caller_address().into(), salt);
+        let (address, _) = deploy_syscall(self.class_hash.read(), salt, array![].span(), false)
+            .unwrap_syscall();
+        self.deployed.write(address);
+    }
+}
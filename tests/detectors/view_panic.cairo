@@ -0,0 +1,69 @@
+#[starknet::interface]
+trait IViewPanic<TContractState> {
+    fn bad_assert(self: @TContractState, amount: u128) -> u128;
+    fn bad_private(self: @TContractState, index: u32) -> felt252;
+    fn bad_overflow(self: @TContractState, amount: u256) -> u256;
+    fn good(self: @TContractState, amount: u128) -> u128;
+    fn good_storage_check(self: @TContractState, amount: u128) -> u128;
+    fn good_storage_overflow(self: @TContractState, amount: u128) -> u256;
+}
+
+#[starknet::contract]
+mod ViewPanic {
+    #[storage]
+    struct Storage {
+        total: u128,
+        supply: u256,
+        values: LegacyMap<u32, felt252>,
+        length: u32,
+    }
+
+    #[abi(embed_v0)]
+    impl ViewPanicImpl of super::IViewPanic<ContractState> {
+        fn bad_assert(self: @ContractState, amount: u128) -> u128 {
+            assert(amount <= 1000, 'amount too big');
+            amount * 2
+        }
+
+        fn bad_private(self: @ContractState, index: u32) -> felt252 {
+            let value = get_value(self, index);
+            if value == 0 {
+                return 1;
+            }
+            value
+        }
+
+        fn bad_overflow(self: @ContractState, amount: u256) -> u256 {
+            amount * 2
+        }
+
+        fn good(self: @ContractState, amount: u128) -> u128 {
+            if amount > 1000 {
+                return 1000;
+            }
+            amount
+        }
+
+        fn good_storage_check(self: @ContractState, amount: u128) -> u128 {
+            let total = self.total.read();
+            assert(total != 0, 'not initialized');
+            if amount > total {
+                return total;
+            }
+            amount
+        }
+
+        fn good_storage_overflow(self: @ContractState, amount: u128) -> u256 {
+            let supply = self.supply.read() * 2;
+            if amount.into() > supply {
+                return supply;
+            }
+            amount.into()
+        }
+    }
+
+    fn get_value(self: @ContractState, index: u32) -> felt252 {
+        assert(index < self.length.read(), 'index out of bounds');
+        self.values.read(index)
+    }
+}
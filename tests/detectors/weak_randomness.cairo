@@ -0,0 +1,57 @@
+#[starknet::contract]
+mod WeakRandomness {
+    use starknet::{get_block_timestamp, get_caller_address, get_tx_info};
+    use core::pedersen::pedersen;
+    use core::poseidon::poseidon_hash_span;
+
+    #[storage]
+    struct Storage {
+        winner: felt252,
+        seed: felt252,
+        secret: felt252,
+    }
+
+    #[external(v0)]
+    fn bad_modulo(ref self: ContractState) -> u256 {
+        let caller: felt252 = get_caller_address().into();
+        let random: u256 = pedersen(caller, get_block_timestamp().into()).into();
+        random % 100
+    }
+
+    #[external(v0)]
+    fn bad_branch(ref self: ContractState) {
+        let tx_hash = get_tx_info().unbox().transaction_hash;
+        let random = poseidon_hash_span(array![tx_hash, 1].span());
+        if random == 0 {
+            self.winner.write(get_caller_address().into());
+        }
+    }
+
+    #[external(v0)]
+    fn good_user_seed(ref self: ContractState, seed: felt252) -> u256 {
+        let random: u256 = pedersen(seed, get_block_timestamp().into()).into();
+        random % 100
+    }
+
+    #[external(v0)]
+    fn good_stored(ref self: ContractState) {
+        let caller: felt252 = get_caller_address().into();
+        self.seed.write(pedersen(caller, get_block_timestamp().into()));
+    }
+
+    #[external(v0)]
+    fn good_stored_secret(ref self: ContractState) -> u256 {
+        let random: u256 = pedersen(self.secret.read(), get_block_timestamp().into()).into();
+        random % 100
+    }
+
+    #[external(v0)]
+    fn good_private_secret(ref self: ContractState) -> u256 {
+        let random: u256 = pedersen(secret(@self), get_block_timestamp().into()).into();
+        random % 100
+    }
+
+    fn secret(self: @ContractState) -> felt252 {
+        self.secret.read()
+    }
+}
@@ -13,6 +13,7 @@ fn test_detectors() {
             )),
             contract_path: None,
             safe_external_calls: Some(vec!["::safe_foo".to_string()]),
+            privileged_sinks: None,
         };
         let core = CoreUnit::new(opts).unwrap();
         let mut results = get_detectors()